@@ -0,0 +1,122 @@
+use crate::days::Day;
+use anyhow::Result;
+use common::report::{duration, Outcome, PartReport};
+use common::{Report, Source};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+/// Every day's report from one run, in order of day.
+#[derive(Debug, Default)]
+pub struct Reports {
+    /// Time from starting the first day to finishing the last.
+    pub wall_ns: u64,
+    pub reports: Vec<Report>,
+}
+
+impl Reports {
+    /// How many days failed to parse or solve.
+    pub fn failures(&self) -> usize {
+        self.reports.iter().filter(|r| r.failed()).count()
+    }
+
+    /// A line counting the days and failures, with the wall time.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} days, {} failed, {} wall time",
+            self.reports.len(),
+            self.failures(),
+            duration(self.wall_ns)
+        )
+    }
+}
+
+/// Runs every day on its `input.txt` across a pool of `jobs` threads, or one per core if `None`.
+/// Each day is parsed once and then both parts run at the same time. Errors and panics are
+/// recorded against the stage they happened in without stopping the other days, and days without
+/// an `input.txt` are skipped.
+pub fn run(days: &[&Day], parts: &[u8], jobs: Option<usize>) -> Result<Reports> {
+    let mut builder = rayon::ThreadPoolBuilder::new();
+    if let Some(jobs) = jobs {
+        builder = builder.num_threads(jobs);
+    }
+    let pool = builder.build()?;
+
+    let start = Instant::now();
+    let mut reports: Vec<Report> = quietly(|| {
+        pool.install(|| {
+            use rayon::prelude::*;
+            days.par_iter().map(|day| run_day(day, parts)).collect()
+        })
+    });
+    let wall_ns = start.elapsed().as_nanos() as u64;
+
+    reports.sort_by_key(|r| r.day);
+    Ok(Reports { wall_ns, reports })
+}
+
+fn run_day(day: &Day, parts: &[u8]) -> Report {
+    if !day.has_input() {
+        return Report::stopped(day.number, None, Outcome::Skipped);
+    }
+    let source = Source::default();
+    let (parse_ns, parsed) = timed(|| {
+        let data = (day.read)(&source)?;
+        day.parse(&data, &source)
+    });
+    let input = match parsed {
+        Ok(input) => input,
+        Err(outcome) => return Report::stopped(day.number, Some(parse_ns), outcome),
+    };
+
+    let solve = |part: u8| {
+        let (ns, answer) = timed(|| day.solver.part(part, input.as_ref()));
+        PartReport {
+            part,
+            ns: Some(ns),
+            outcome: answer.map_or_else(|o| o, |answer| Outcome::Solved { answer }),
+        }
+    };
+    let parts = match parts {
+        [a, b] => {
+            let (a, b) = rayon::join(|| solve(*a), || solve(*b));
+            vec![a, b]
+        }
+        parts => parts.iter().map(|&p| solve(p)).collect(),
+    };
+    Report::new(day.number, parse_ns, parts)
+}
+
+/// Runs `f` without the default hook printing panics, for callers that report them themselves.
+pub(crate) fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+/// Times `f`, turning its errors and panics into outcomes.
+pub(crate) fn timed<T>(f: impl FnOnce() -> Result<T>) -> (u64, Result<T, Outcome>) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let ns = start.elapsed().as_nanos() as u64;
+    let result = match result {
+        Ok(Ok(v)) => Ok(v),
+        Ok(Err(err)) => Err(Outcome::error(err)),
+        Err(payload) => Err(Outcome::Panicked {
+            message: panic_message(payload),
+        }),
+    };
+    (ns, result)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "panicked".into(),
+        },
+    }
+}
@@ -0,0 +1,103 @@
+//! A global allocator that counts allocations, installed when the `count-alloc` feature is on.
+//! The counts are global, so measurements are only meaningful while nothing else runs.
+
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+/// Whether allocations are being counted in this build.
+pub const ENABLED: bool = cfg!(feature = "count-alloc");
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting what passes through it.
+pub struct Counting;
+
+// SAFETY: every call is passed straight on to `System`; only the counters are extra.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grew(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grew(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    /// Counts as an allocation of however much the block grew by.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            let (old, new_size) = (layout.size() as u64, new_size as u64);
+            LIVE.fetch_sub(old, Relaxed);
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            ALLOCATED.fetch_add(new_size.saturating_sub(old), Relaxed);
+            let live = LIVE.fetch_add(new_size, Relaxed) + new_size;
+            PEAK.fetch_max(live, Relaxed);
+        }
+        new
+    }
+}
+
+fn grew(size: u64) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    ALLOCATED.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+/// Memory used while running some code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    /// Allocations and reallocations made.
+    pub allocations: u64,
+    /// Bytes requested across all of them.
+    pub bytes: u64,
+    /// Most bytes held at once beyond what was held before.
+    pub peak: u64,
+}
+
+/// Runs `f`, counting what it allocates. Everything is zero unless [`ENABLED`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = ALLOCATED.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+
+    let value = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: ALLOCATED.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (value, usage)
+}
+
+/// Formats a byte count with a binary unit suited to its magnitude.
+pub fn bytes(n: u64) -> String {
+    match n {
+        n if n < 1 << 10 => format!("{}B", n),
+        n if n < 1 << 20 => format!("{:.1}KiB", n as f64 / 1024.0),
+        n if n < 1 << 30 => format!("{:.1}MiB", n as f64 / (1 << 20) as f64),
+        n => format!("{:.2}GiB", n as f64 / (1 << 30) as f64),
+    }
+}
@@ -0,0 +1,225 @@
+use crate::alloc::{self, Usage};
+use crate::days::Day;
+use anyhow::{Context, Result};
+use common::report::duration;
+use common::{Source, Unsolved};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part1"),
+            Self::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Summary of the time one stage of a day took across all iterations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    /// Memory used by the first iteration, when built with the `count-alloc` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+impl Timing {
+    fn new(day: u8, stage: Stage, mut samples: Vec<Duration>, memory: Option<Usage>) -> Self {
+        samples.sort();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        Self {
+            day,
+            stage,
+            iterations: samples.len(),
+            min_ns: ns(samples[0]),
+            median_ns: ns(samples[samples.len() / 2]),
+            max_ns: ns(samples[samples.len() - 1]),
+            memory,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub timings: Vec<Timing>,
+    /// Days left out for having no `input.txt`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<u8>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, text).with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn find(&self, day: u8, stage: Stage) -> Option<&Timing> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.stage == stage)
+    }
+}
+
+/// Times parsing and each solved part of every day over `iterations` runs on its `input.txt`.
+/// With allocations counted, the first run of each stage also records its memory use. Days
+/// without an `input.txt` are skipped.
+pub fn run(days: &[&Day], iterations: usize) -> Result<Report> {
+    anyhow::ensure!(iterations > 0, "need at least one iteration");
+
+    let mut report = Report::default();
+    for day in days {
+        if !day.has_input() {
+            report.skipped.push(day.number);
+            continue;
+        }
+        let source = Source::default();
+        let data = (day.read)(&source)?;
+        let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+        let mut memory = [None; 3];
+        let mut solved = [true, true];
+
+        for i in 0..iterations {
+            let counted = alloc::ENABLED && i == 0;
+            let start = Instant::now();
+            let (input, usage) = alloc::measure(|| day.parse(&data, &source));
+            samples[0].push(start.elapsed());
+            let input = input?;
+            if counted {
+                memory[0] = Some(usage);
+            }
+
+            for part in 1..=2 {
+                if !solved[part as usize - 1] {
+                    continue;
+                }
+                let start = Instant::now();
+                let (answer, usage) = alloc::measure(|| day.solver.part(part, input.as_ref()));
+                let elapsed = start.elapsed();
+                match answer {
+                    Ok(_) => {
+                        samples[part as usize].push(elapsed);
+                        if counted {
+                            memory[part as usize] = Some(usage);
+                        }
+                    }
+                    Err(err) if err.is::<Unsolved>() => solved[part as usize - 1] = false,
+                    Err(err) => {
+                        return Err(err.context(format!("day {} part {}", day.number, part)))
+                    }
+                }
+            }
+        }
+
+        for ((stage, samples), memory) in [Stage::Parse, Stage::Part1, Stage::Part2]
+            .into_iter()
+            .zip(samples)
+            .zip(memory)
+        {
+            if !samples.is_empty() {
+                report
+                    .timings
+                    .push(Timing::new(day.number, stage, samples, memory));
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Prints the timings as a table, with the change in median time against `baseline` if given.
+pub fn print(report: &Report, baseline: Option<&Report>) {
+    print!("Day  Stage  {:>10}  {:>10}  {:>10}", "Min", "Median", "Max");
+    if baseline.is_some() {
+        print!("  {:>10}  {:>8}", "Baseline", "Change");
+    }
+    println!();
+
+    for t in &report.timings {
+        print!(
+            "{:>3}  {:5}  {:>10}  {:>10}  {:>10}",
+            t.day,
+            t.stage,
+            duration(t.min_ns),
+            duration(t.median_ns),
+            duration(t.max_ns)
+        );
+        if let Some(baseline) = baseline {
+            match baseline.find(t.day, t.stage) {
+                Some(b) => {
+                    let change = (t.median_ns as f64 - b.median_ns as f64) / b.median_ns as f64;
+                    print!("  {:>10}  {:>+7.1}%", duration(b.median_ns), change * 100.0);
+                }
+                None => print!("  {:>10}  {:>8}", "-", "-"),
+            }
+        }
+        println!();
+    }
+
+    if report.timings.iter().any(|t| t.memory.is_some()) {
+        println!();
+        print_memory(report, baseline);
+    }
+
+    if !report.skipped.is_empty() {
+        println!();
+        let skipped: Vec<String> = report.skipped.iter().map(u8::to_string).collect();
+        println!("skipped days with no input.txt: {}", skipped.join(", "));
+    }
+}
+
+/// Prints the memory each stage used, with the change in bytes allocated against `baseline`.
+fn print_memory(report: &Report, baseline: Option<&Report>) {
+    print!(
+        "Day  Stage  {:>10}  {:>10}  {:>10}",
+        "Allocs", "Allocated", "Peak"
+    );
+    if baseline.is_some() {
+        print!("  {:>10}  {:>8}", "Baseline", "Change");
+    }
+    println!();
+
+    for t in &report.timings {
+        let Some(m) = t.memory else { continue };
+        print!(
+            "{:>3}  {:5}  {:>10}  {:>10}  {:>10}",
+            t.day,
+            t.stage,
+            m.allocations,
+            alloc::bytes(m.bytes),
+            alloc::bytes(m.peak)
+        );
+        if let Some(baseline) = baseline {
+            match baseline.find(t.day, t.stage).and_then(|b| b.memory) {
+                Some(b) if b.bytes > 0 => {
+                    let change = (m.bytes as f64 - b.bytes as f64) / b.bytes as f64;
+                    print!("  {:>10}  {:>+7.1}%", alloc::bytes(b.bytes), change * 100.0);
+                }
+                Some(b) => print!("  {:>10}  {:>8}", alloc::bytes(b.bytes), "-"),
+                None => print!("  {:>10}  {:>8}", "-", "-"),
+            }
+        }
+        println!();
+    }
+}
@@ -0,0 +1,207 @@
+use crate::days::Day;
+use crate::manifest::{Answer, Manifest};
+use anyhow::Result;
+use common::{Source, Unsolved};
+
+pub struct Outcome {
+    pub day: u8,
+    pub case: String,
+    pub part: u8,
+    pub status: Status,
+}
+
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+}
+
+/// Runs every case in each day's manifest against its expected answers.
+pub fn run(days: &[&Day]) -> Result<Vec<Outcome>> {
+    let mut outcomes = Vec::new();
+    for day in days {
+        let manifest = Manifest::load(day)?;
+        for case in &manifest.cases {
+            let input = case.read(day).and_then(|data| match &case.file {
+                Some(file) => day.parse(&data, &Source::Embedded(file.clone())),
+                None => Ok(day.solver.parse(&data).map_err(|err| {
+                    err.in_file(format!("{} case {:?}", Manifest::FILE, case.name()))
+                })?),
+            });
+
+            for part in 1..=2 {
+                let Some(expected) = case.expected(part) else {
+                    continue;
+                };
+                let status = match &input {
+                    Err(err) => Status::Error(format!("{:#}", err)),
+                    Ok(input) => match day.solver.part(part, input.as_ref()) {
+                        Ok(actual) if expected.matches(&actual) => Status::Pass,
+                        Ok(actual) => Status::Fail {
+                            expected: expected.to_string(),
+                            actual,
+                        },
+                        Err(err) => Status::Error(format!("{:#}", err)),
+                    },
+                };
+                outcomes.push(Outcome {
+                    day: day.number,
+                    case: case.name(),
+                    part,
+                    status,
+                });
+            }
+        }
+    }
+    Ok(outcomes)
+}
+
+/// Prints a pass/fail table, followed by a diff for each failure.
+pub fn report(outcomes: &[Outcome]) {
+    let width = outcomes
+        .iter()
+        .map(|o| o.case.len())
+        .chain(["Case".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("Day  {:width$}  Part  Result", "Case");
+    for o in outcomes {
+        let result = match &o.status {
+            Status::Pass => "ok".to_string(),
+            Status::Fail { .. } => "FAIL".to_string(),
+            Status::Error(err) => format!("ERROR: {}", err),
+        };
+        println!("{:>3}  {:width$}  {:>4}  {}", o.day, o.case, o.part, result);
+    }
+
+    for o in outcomes {
+        if let Status::Fail { expected, actual } = &o.status {
+            println!();
+            println!("day {} {} part {}:", o.day, o.case, o.part);
+            diff(expected, actual);
+        }
+    }
+
+    let passed = outcomes
+        .iter()
+        .filter(|o| matches!(o.status, Status::Pass))
+        .count();
+    println!();
+    println!("{} passed, {} failed", passed, outcomes.len() - passed);
+}
+
+/// Prints expected (`-`) and actual (`+`) lines side by side, marking unchanged lines with a space.
+fn diff(expected: &str, actual: &str) {
+    let expected: Vec<&str> = expected.trim_end().lines().collect();
+    let actual: Vec<&str> = actual.trim_end().lines().collect();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => println!("    {}", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    println!("  - {}", e);
+                }
+                if let Some(a) = a {
+                    println!("  + {}", a);
+                }
+            }
+        }
+    }
+}
+
+/// An answer saved by [`record`], along with the one it replaced.
+pub struct Recorded {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub previous: Option<String>,
+}
+
+/// What [`record`] saved, and the days it left out for having no `input.txt`.
+pub struct Recording {
+    pub recorded: Vec<Recorded>,
+    pub skipped: Vec<u8>,
+}
+
+/// Solves each day's `input.txt` and saves the answers into its manifest on disk, so later checks
+/// flag any answer that changes.
+pub fn record(days: &[&Day]) -> Result<Recording> {
+    let mut recorded = Vec::new();
+    let mut skipped = Vec::new();
+    for day in days {
+        if !day.has_input() {
+            skipped.push(day.number);
+            continue;
+        }
+        let source = Source::default();
+        let data = (day.read)(&source)?;
+        let input = day.parse(&data, &source)?;
+
+        let path = day.data_dir().join(Manifest::FILE);
+        let mut manifest = Manifest::open(&path)?;
+        let Source::Embedded(file) = &source else {
+            unreachable!("default source is embedded");
+        };
+        let case = manifest.case_for_file(file);
+
+        for part in 1..=2 {
+            let answer = match day.solver.part(part, input.as_ref()) {
+                Ok(answer) => answer,
+                Err(err) if err.is::<Unsolved>() => continue,
+                Err(err) => return Err(err.context(format!("day {} part {}", day.number, part))),
+            };
+            let slot = match part {
+                1 => &mut case.part1,
+                _ => &mut case.part2,
+            };
+            let previous = slot.replace(Answer::from_output(&answer));
+            recorded.push(Recorded {
+                day: day.number,
+                part,
+                answer,
+                previous: previous.map(|a| a.to_string()),
+            });
+        }
+
+        manifest.save(&path)?;
+    }
+    Ok(Recording { recorded, skipped })
+}
+
+/// Prints recorded answers, noting which ones changed.
+pub fn report_recorded(recording: &Recording) {
+    let recorded = &recording.recorded;
+    println!("Day  Part  Answer");
+    for r in recorded {
+        let note = match &r.previous {
+            None => " (new)",
+            Some(p) if Answer::from_output(p).matches(&r.answer) => "",
+            Some(_) => " (changed)",
+        };
+        if r.answer.contains('\n') {
+            println!("{:>3}  {:>4}{}", r.day, r.part, note);
+            for line in r.answer.lines() {
+                println!("           {}", line);
+            }
+        } else {
+            println!("{:>3}  {:>4}  {}{}", r.day, r.part, r.answer, note);
+        }
+    }
+
+    for r in recorded {
+        if let Some(previous) = &r.previous {
+            if !Answer::from_output(previous).matches(&r.answer) {
+                println!();
+                println!("day {} part {}:", r.day, r.part);
+                diff(previous, &r.answer);
+            }
+        }
+    }
+
+    if !recording.skipped.is_empty() {
+        let skipped: Vec<String> = recording.skipped.iter().map(u8::to_string).collect();
+        println!();
+        println!("skipped days with no input.txt: {}", skipped.join(", "));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::days;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Settings for talking to Advent of Code, read from `aoc.toml` at the workspace root and
+/// overridden by `AOC_*` environment variables.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Value of the `session` cookie from a logged in browser.
+    pub session: Option<String>,
+    /// Server to talk to instead of adventofcode.com.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub const FILE: &'static str = "aoc.toml";
+
+    pub fn load() -> Result<Self> {
+        let mut config = Self::open(&days::workspace_dir().join(Self::FILE))?;
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    /// Reads a config file, treating a missing file as empty.
+    pub fn open(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::{Context, Result};
+use common::{Generate, ParseError, Solution, Solver, Source, Stream};
+use grid::Grid;
+use rand::rngs::StdRng;
+use rust_embed::RustEmbed;
+use std::any::Any;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use viz::{Animate, Draw, Palette, Recorder};
+
+pub struct Day {
+    pub number: u8,
+    /// Reads puzzle input, looking up embedded files in the day's `data/` directory.
+    pub read: fn(&Source) -> Result<String>,
+    /// Opens puzzle input for reading a piece at a time, like `read`.
+    pub open: fn(&Source) -> Result<Box<dyn BufRead>>,
+    /// Whether puzzle input is there to read, like `read`.
+    pub exists: fn(&Source) -> bool,
+    pub solver: Box<dyn Solver>,
+    /// Produces random input of roughly the given size.
+    pub generate: fn(&mut StdRng, usize) -> String,
+    /// Records a part's intermediate states, for days that implement [`Animate`].
+    pub animate: Option<AnimateFn>,
+    /// Draws a part of a parsed input, for days that implement [`Draw`].
+    pub draw: Option<DrawFn>,
+    /// Solves both parts in one pass over a reader, for days that implement [`Stream`].
+    pub stream: Option<StreamFn>,
+}
+
+/// Records one part of a parsed input.
+pub type AnimateFn = fn(&dyn Any, u8, &mut Recorder) -> Result<()>;
+
+/// Draws one part of a parsed input, with the colours to draw it in.
+pub type DrawFn = fn(&dyn Any, u8) -> Result<(Grid<char>, Palette)>;
+
+/// Solves both parts from a reader, with the answers rendered as text.
+pub type StreamFn = fn(&mut dyn BufRead) -> Result<(String, String)>;
+
+impl Day {
+    fn new<S: Solution + Generate + 'static, E: RustEmbed>(number: u8) -> Self {
+        Self {
+            number,
+            read: Source::read::<E>,
+            open: Source::open::<E>,
+            exists: Source::exists::<E>,
+            solver: common::solver::<S>(),
+            generate: S::generate,
+            animate: None,
+            draw: None,
+            stream: None,
+        }
+    }
+
+    fn animated<S: Animate + 'static>(mut self) -> Self {
+        self.animate = Some(|input, part, recorder| {
+            let input = input
+                .downcast_ref::<S::Input>()
+                .context("input of the wrong type")?;
+            S::animate(input, part, recorder)
+        });
+        self
+    }
+
+    fn drawn<S: Draw + 'static>(mut self) -> Self {
+        self.draw = Some(|input, part| {
+            let input = input
+                .downcast_ref::<S::Input>()
+                .context("input of the wrong type")?;
+            Ok((S::draw(input, part)?, S::palette()))
+        });
+        self
+    }
+
+    fn streamed<S: Stream + 'static>(mut self) -> Self {
+        self.stream = Some(|reader| {
+            let (one, two) = S::stream(reader)?;
+            Ok((one.to_string(), two.to_string()))
+        });
+        self
+    }
+
+    /// Parses input read from `source`, naming it in any parse error.
+    pub fn parse(&self, data: &str, source: &Source) -> Result<Box<dyn Any + Send + Sync>> {
+        Ok(self
+            .solver
+            .parse(data)
+            .map_err(|err| err.in_file(self.file(source)))?)
+    }
+
+    /// Solves both parts while reading `source`, naming it in any parse error.
+    pub fn stream(&self, source: &Source) -> Result<(String, String)> {
+        let stream = self
+            .stream
+            .with_context(|| format!("day {} can't be streamed", self.number))?;
+        let mut reader = (self.open)(source)?;
+        stream(&mut reader).map_err(|err| match err.downcast::<ParseError>() {
+            Ok(err) => err.in_file(self.file(source)).into(),
+            Err(err) => err,
+        })
+    }
+
+    /// Name of the input file for messages, relative to the workspace when embedded.
+    fn file(&self, source: &Source) -> String {
+        match source {
+            Source::Embedded(_) => format!("day{:02}/{}", self.number, source.name()),
+            source => source.name(),
+        }
+    }
+
+    /// Whether the day has an `input.txt`; freshly scaffolded days don't until it is fetched.
+    pub fn has_input(&self) -> bool {
+        (self.exists)(&Source::default())
+    }
+
+    /// The day's `data/` directory in the workspace, for tools that write files back.
+    pub fn data_dir(&self) -> PathBuf {
+        data_dir(self.number)
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01, day01::Data>(1).streamed::<day01::Day01>(),
+        Day::new::<day02::Day02, day02::Data>(2).streamed::<day02::Day02>(),
+        Day::new::<day03::Day03, day03::Data>(3),
+        Day::new::<day04::Day04, day04::Data>(4).streamed::<day04::Day04>(),
+        Day::new::<day05::Day05, day05::Data>(5),
+        Day::new::<day06::Day06, day06::Data>(6).streamed::<day06::Day06>(),
+        Day::new::<day07::Day07, day07::Data>(7),
+        Day::new::<day08::Day08, day08::Data>(8).drawn::<day08::Day08>(),
+        Day::new::<day09::Day09, day09::Data>(9)
+            .streamed::<day09::Day09>()
+            .animated::<day09::Day09>()
+            .drawn::<day09::Day09>(),
+        Day::new::<day10::Day10, day10::Data>(10)
+            .streamed::<day10::Day10>()
+            .animated::<day10::Day10>(),
+        Day::new::<day11::Day11, day11::Data>(11),
+        Day::new::<day12::Day12, day12::Data>(12)
+            .animated::<day12::Day12>()
+            .drawn::<day12::Day12>(),
+        Day::new::<day13::Day13, day13::Data>(13),
+        Day::new::<day14::Day14, day14::Data>(14)
+            .animated::<day14::Day14>()
+            .drawn::<day14::Day14>(),
+    ]
+}
+
+/// Root of the workspace this binary was built from.
+pub fn workspace_dir() -> PathBuf {
+    let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+    aoc.parent().unwrap_or(aoc).to_path_buf()
+}
+
+/// The `data/` directory of a day's crate, whether or not the crate exists yet.
+pub fn data_dir(number: u8) -> PathBuf {
+    workspace_dir()
+        .join(format!("day{:02}", number))
+        .join("data")
+}
+
+/// Picks out a single day, or every day when `day` is `None`.
+pub fn select(days: &[Day], day: Option<u8>) -> Result<Vec<&Day>> {
+    match day {
+        Some(n) => Ok(vec![days
+            .iter()
+            .find(|d| d.number == n)
+            .with_context(|| format!("no solution for day {}", n))?]),
+        None => Ok(days.iter().collect()),
+    }
+}
@@ -0,0 +1,98 @@
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+const USER_AGENT: &str = "github.com/brettbuddin/advent2022";
+
+/// Client for the Advent of Code site, or anything serving the same routes.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let session = config
+            .session
+            .as_deref()
+            .context("no session token: set AOC_SESSION or session in aoc.toml")?;
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        Ok(Self::new(base_url, session))
+    }
+
+    /// URL of a day's puzzle page, which answers are posted beneath.
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        match self.request("GET", &url).call() {
+            Ok(resp) => Ok(resp.into_string()?),
+            Err(ureq::Error::Status(404, _)) => bail!("day {} input isn't available yet", day),
+            Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(500, _)) => {
+                bail!("server rejected the request; is the session token still valid?")
+            }
+            Err(err) => Err(err).with_context(|| format!("fetching {}", url)),
+        }
+    }
+
+    /// Posts an answer for one part of a day, returning the page the server responds with.
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        match self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)])
+        {
+            Ok(resp) => Ok(resp.into_string()?),
+            Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(500, _)) => {
+                bail!("server rejected the request; is the session token still valid?")
+            }
+            Err(err) => Err(err).with_context(|| format!("posting to {}", url)),
+        }
+    }
+}
+
+pub enum Fetched {
+    /// The input was already on disk, so nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure a day's `input.txt` exists in `dir`, downloading it only if it's missing.
+pub fn fetch(config: &Config, day: u8, dir: &Path) -> Result<Fetched> {
+    let path = dir.join("input.txt");
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    if !dir.exists() {
+        bail!(
+            "{} doesn't exist; create the day's crate first",
+            dir.display()
+        );
+    }
+
+    let input = Client::from_config(config)?.input(day)?;
+    std::fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
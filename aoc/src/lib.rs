@@ -0,0 +1,11 @@
+pub mod all;
+pub mod alloc;
+pub mod bench;
+pub mod check;
+pub mod config;
+pub mod days;
+pub mod fetch;
+pub mod manifest;
+pub mod scaffold;
+pub mod stress;
+pub mod submit;
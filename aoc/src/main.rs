@@ -0,0 +1,432 @@
+use anyhow::{bail, Context, Result};
+use aoc::config::Config;
+use aoc::{all, bench, check, days, fetch, scaffold, stress, submit};
+use clap::{Parser, Subcommand};
+use common::report::{self, Format};
+use common::{Report, Source};
+use std::path::PathBuf;
+use std::time::Instant;
+use viz::{image, Recorder};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for a single day or every day.
+    Run {
+        /// Day to run.
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+
+        /// Only run this part of the day.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Run every day in parallel and print a summary table.
+        #[arg(long)]
+        all: bool,
+
+        /// Read input from this file, or `-` for stdin, instead of the embedded `input.txt`.
+        #[arg(long, conflicts_with = "all")]
+        input: Option<Source>,
+
+        /// Solve both parts in one pass while reading the input, for inputs too large to hold in
+        /// memory. Only days 01, 02, 04, 06, 09 and 10 can be streamed.
+        #[arg(long, conflicts_with = "all")]
+        stream: bool,
+
+        /// How to print the answers: text, json or tsv.
+        #[arg(long, default_value = "text")]
+        format: Format,
+
+        /// With `--all`, number of threads to run days on. Defaults to one per core.
+        #[arg(long, requires = "all")]
+        jobs: Option<usize>,
+
+        /// With `--all`, also write the results to this JSON file.
+        #[arg(long, requires = "all")]
+        json: Option<PathBuf>,
+    },
+    /// Check solutions against the expected answers in each day's `data/answers.toml`.
+    Check {
+        /// Only check this day.
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Save the current answers for each day's `input.txt` instead of checking.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and each part of every day on its `input.txt`.
+    Bench {
+        /// Only benchmark this day.
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Number of times to run each day.
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Write the timings to this JSON file.
+        #[arg(long)]
+        json: Option<PathBuf>,
+
+        /// Compare against timings previously written with `--json`.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Download a day's input into its `data/input.txt`, unless it's already there.
+    ///
+    /// The session token is read from `AOC_SESSION` or `session` in `aoc.toml` at the workspace
+    /// root. `AOC_BASE_URL` or `base_url` points at a different server.
+    Fetch {
+        /// Day to fetch.
+        #[arg(long)]
+        day: u8,
+    },
+    /// Print random input for a day.
+    Generate {
+        /// Day to generate input for.
+        #[arg(long)]
+        day: u8,
+
+        /// Rough size of the input, such as the number of lines or the grid width.
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the random number generator. Defaults to a random seed.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Solve many generated inputs for each day, reporting any that fail to parse or solve.
+    Stress {
+        /// Only stress this day.
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Rough size of each input.
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Number of inputs to try for each day.
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+
+        /// Seed of the first input; later inputs use the seeds after it, wrapping to 0 past the
+        /// largest. Defaults to a random seed.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Watch a part of a day solve itself in the terminal.
+    ///
+    /// Space pauses, the arrow keys step a frame at a time, `+` and `-` change the speed and `q`
+    /// quits. Only days 09, 10, 12 and 14 can be animated.
+    Animate {
+        /// Day to animate.
+        #[arg(long)]
+        day: u8,
+
+        /// Part to animate.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read input from this file, or `-` for stdin, instead of the embedded `input.txt`.
+        #[arg(long)]
+        input: Option<Source>,
+
+        /// Frames per second to start playing at.
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+
+        /// Most frames to keep. Longer runs are sampled evenly.
+        #[arg(long, default_value_t = 2000)]
+        frames: usize,
+    },
+    /// Save a picture of a part of a day as an SVG or binary PPM image.
+    ///
+    /// Only days 08, 09, 12 and 14 can be drawn.
+    Draw {
+        /// Day to draw.
+        #[arg(long)]
+        day: u8,
+
+        /// Part to draw.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read input from this file, or `-` for stdin, instead of the embedded `input.txt`.
+        #[arg(long)]
+        input: Option<Source>,
+
+        /// Image to write, as SVG or PPM depending on its extension.
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Pixels per cell.
+        #[arg(long, default_value_t = 8)]
+        scale: usize,
+    },
+    /// Create a day's crate from `template/` and register it with the workspace and `aoc`.
+    New {
+        /// Day to create.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Submit an answer for one part of a day and record the verdict in its `data/submissions.toml`.
+    ///
+    /// Answers already known to be wrong, and submissions made before the server's requested wait
+    /// has passed, are refused without contacting the server.
+    Submit {
+        /// Day to submit.
+        #[arg(long)]
+        day: u8,
+
+        /// Part to submit.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit, instead of solving the day's `input.txt`.
+        #[arg(long)]
+        answer: Option<String>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all: false,
+            input,
+            stream,
+            format,
+            ..
+        } => {
+            let days = days::all();
+            let source = input.unwrap_or_default();
+            let parts = match part {
+                Some(n) => vec![n],
+                None => vec![1, 2],
+            };
+            let mut reports = Vec::new();
+            for day in days::select(&days, day)? {
+                reports.push(if stream {
+                    run_streamed(day, &parts, &source)?
+                } else {
+                    run(day, &parts, &source)?
+                });
+            }
+            print!("{}", report::render(&reports, format));
+            let failed = reports.iter().filter(|r| r.failed()).count();
+            if failed > 0 {
+                bail!("{} days failed", failed);
+            }
+        }
+        Command::Run {
+            part,
+            all: true,
+            jobs,
+            json,
+            format,
+            ..
+        } => {
+            let days = days::all();
+            let parts = match part {
+                Some(n) => vec![n],
+                None => vec![1, 2],
+            };
+            let all = all::run(&days::select(&days, None)?, &parts, jobs)?;
+            print!("{}", report::render(&all.reports, format));
+            if format == Format::Text {
+                println!();
+                println!("{}", all.summary());
+            }
+            if let Some(path) = json {
+                std::fs::write(&path, report::render(&all.reports, Format::Json))
+                    .with_context(|| format!("writing {}", path.display()))?;
+            }
+            if all.failures() > 0 {
+                bail!("{} days failed", all.failures());
+            }
+        }
+        Command::Check { day, record } => {
+            let days = days::all();
+            if record {
+                let recording = check::record(&days::select(&days, day)?)?;
+                check::report_recorded(&recording);
+                return Ok(());
+            }
+            let outcomes = check::run(&days::select(&days, day)?)?;
+            check::report(&outcomes);
+            if outcomes
+                .iter()
+                .any(|o| !matches!(o.status, check::Status::Pass))
+            {
+                bail!("some answers did not match");
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            json,
+            baseline,
+        } => {
+            let days = days::all();
+            let baseline = baseline.as_deref().map(bench::Report::load).transpose()?;
+            let report = bench::run(&days::select(&days, day)?, iterations)?;
+            bench::print(&report, baseline.as_ref());
+            if let Some(path) = json {
+                report.save(&path)?;
+            }
+        }
+        Command::Fetch { day } => {
+            match fetch::fetch(&Config::load()?, day, &days::data_dir(day))? {
+                fetch::Fetched::Cached(path) => {
+                    println!("day {} already cached at {}", day, path.display())
+                }
+                fetch::Fetched::Downloaded(path) => {
+                    println!("day {} saved to {}", day, path.display())
+                }
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            let days = days::all();
+            let day = days::select(&days, Some(day))?[0];
+            print!(
+                "{}",
+                stress::generate(day, size, seed.unwrap_or_else(rand::random))
+            );
+        }
+        Command::Stress {
+            day,
+            size,
+            count,
+            seed,
+        } => {
+            let days = days::all();
+            let days = days::select(&days, day)?;
+            let seed = seed.unwrap_or_else(rand::random);
+            println!(
+                "{} inputs of size {} for {} days from seed {}",
+                count,
+                size,
+                days.len(),
+                seed
+            );
+            let failures = stress::run(&days, size, count, seed);
+            for f in &failures {
+                let message = match &f.outcome {
+                    report::Outcome::Error { message } => format!("error: {}", message),
+                    report::Outcome::Panicked { message } => format!("panicked: {}", message),
+                    _ => continue,
+                };
+                println!("day {} seed {} {}: {}", f.day, f.seed, f.stage, message);
+                println!(
+                    "  reproduce with: aoc generate --day {} --size {} --seed {}",
+                    f.day, size, f.seed
+                );
+            }
+            if !failures.is_empty() {
+                bail!("{} inputs failed", failures.len());
+            }
+            println!("all passed");
+        }
+        Command::Animate {
+            day,
+            part,
+            input,
+            fps,
+            frames,
+        } => {
+            let days = days::all();
+            let day = days::select(&days, Some(day))?[0];
+            let animate = day
+                .animate
+                .with_context(|| format!("day {} can't be animated", day.number))?;
+            let source = input.unwrap_or_default();
+            let input = day.parse(&(day.read)(&source)?, &source)?;
+            let mut recorder = Recorder::new(frames);
+            animate(input.as_ref(), part, &mut recorder)?;
+            viz::play(recorder.frames(), fps)?;
+        }
+        Command::Draw {
+            day,
+            part,
+            input,
+            output,
+            scale,
+        } => {
+            let days = days::all();
+            let day = days::select(&days, Some(day))?[0];
+            let draw = day
+                .draw
+                .with_context(|| format!("day {} can't be drawn", day.number))?;
+            let format = image::Format::of(&output)?;
+            let source = input.unwrap_or_default();
+            let input = day.parse(&(day.read)(&source)?, &source)?;
+            let (grid, palette) = draw(input.as_ref(), part)?;
+            image::save(&output, &grid, &palette, scale)?;
+            println!("{:?} saved to {}", format, output.display());
+        }
+        Command::New { day } => {
+            let scaffolded = scaffold::new(&days::workspace_dir(), day)?;
+            for path in &scaffolded.created {
+                println!("created {}", path.display());
+            }
+            for path in &scaffolded.registered {
+                println!("updated {}", path.display());
+            }
+        }
+        Command::Submit { day, part, answer } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let days = days::all();
+                    let day = days::select(&days, Some(day))?[0];
+                    let source = Source::default();
+                    let input = day.parse(&(day.read)(&source)?, &source)?;
+                    day.solver.part(part, input.as_ref())?
+                }
+            };
+            let submission =
+                submit::submit(&Config::load()?, day, part, &answer, &days::data_dir(day))?;
+            println!(
+                "day {} part {}: {} is {}",
+                day, part, submission.answer, submission.verdict
+            );
+            if let Some(until) = submission.wait_until {
+                println!("next answer accepted in {}s", until - submission.at);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run(day: &days::Day, parts: &[u8], source: &Source) -> Result<Report> {
+    let data = (day.read)(source)?;
+    let start = Instant::now();
+    let input = day.parse(&data, source)?;
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    Ok(Report::solve_parsed(
+        day.number,
+        parse_ns,
+        day.solver.as_ref(),
+        input.as_ref(),
+        parts,
+    ))
+}
+
+fn run_streamed(day: &days::Day, parts: &[u8], source: &Source) -> Result<Report> {
+    let start = Instant::now();
+    let (one, two) = day.stream(source)?;
+    let total_ns = start.elapsed().as_nanos() as u64;
+    Ok(Report::streamed(day.number, total_ns, [one, two], parts))
+}
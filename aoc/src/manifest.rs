@@ -0,0 +1,169 @@
+use crate::days::Day;
+use anyhow::{Context, Result};
+use common::Source;
+use serde::Deserialize;
+use std::fmt::{self, Write};
+use std::path::Path;
+
+/// Expected answers for a day's inputs, read from `data/answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "case")]
+    pub cases: Vec<Case>,
+}
+
+impl Manifest {
+    pub const FILE: &'static str = "answers.toml";
+
+    pub fn load(day: &Day) -> Result<Self> {
+        let text = (day.read)(&Source::Embedded(Self::FILE.into()))?;
+        toml::from_str(&text).with_context(|| format!("day {} {}", day.number, Self::FILE))
+    }
+
+    /// Reads a manifest from disk, treating a missing file as empty.
+    pub fn open(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_toml()).with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Finds the case for `file`, adding an empty one if there isn't one yet.
+    pub fn case_for_file(&mut self, file: &str) -> &mut Case {
+        let idx = match self
+            .cases
+            .iter()
+            .position(|c| c.file.as_deref() == Some(file))
+        {
+            Some(idx) => idx,
+            None => {
+                self.cases.push(Case {
+                    file: Some(file.into()),
+                    input: None,
+                    part1: None,
+                    part2: None,
+                });
+                self.cases.len() - 1
+            }
+        };
+        &mut self.cases[idx]
+    }
+
+    /// Renders the manifest by hand so multi-line answers stay readable as literal strings.
+    fn to_toml(&self) -> String {
+        let mut out = String::new();
+        for (i, case) in self.cases.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str("[[case]]\n");
+            if let Some(file) = &case.file {
+                writeln!(out, "file = {}", toml_string(file)).unwrap();
+            }
+            if let Some(input) = &case.input {
+                writeln!(out, "input = {}", toml_string(input)).unwrap();
+            }
+            for (part, answer) in [(1, &case.part1), (2, &case.part2)] {
+                if let Some(answer) = answer {
+                    writeln!(out, "part{} = {}", part, answer.to_toml()).unwrap();
+                }
+            }
+        }
+        out
+    }
+}
+
+fn toml_string(s: &str) -> String {
+    toml::Value::String(s.to_string()).to_string()
+}
+
+/// An input along with the answers it should produce.
+#[derive(Debug, Deserialize)]
+pub struct Case {
+    /// File in the day's `data/` directory holding the input.
+    pub file: Option<String>,
+    /// Input given inline, for examples too short to warrant a file.
+    pub input: Option<String>,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Case {
+    pub fn name(&self) -> String {
+        match (&self.file, &self.input) {
+            (Some(file), _) => file.clone(),
+            (None, Some(input)) => {
+                let line = input.lines().next().unwrap_or_default();
+                match line.char_indices().nth(24) {
+                    Some((i, _)) => format!("{}...", &line[..i]),
+                    None => line.to_string(),
+                }
+            }
+            (None, None) => "(no input)".into(),
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String> {
+        match (&self.file, &self.input) {
+            (Some(file), _) => (day.read)(&Source::Embedded(file.clone())),
+            (None, Some(input)) => Ok(input.clone()),
+            (None, None) => anyhow::bail!("case has neither file nor input"),
+        }
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// An expected answer. Numbers may be written bare; anything else as a string.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// Wraps a solver's output, keeping integers as numbers.
+    pub fn from_output(s: &str) -> Self {
+        match s.parse() {
+            Ok(n) => Self::Number(n),
+            Err(_) => Self::Text(s.to_string()),
+        }
+    }
+
+    fn to_toml(&self) -> String {
+        match self {
+            Self::Number(n) => n.to_string(),
+            Self::Text(s) if s.contains('\n') && !s.contains("'''") => {
+                format!("'''\n{}'''", s)
+            }
+            Self::Text(s) => toml_string(s),
+        }
+    }
+
+    /// Compares against a solver's output, ignoring trailing whitespace so multi-line answers
+    /// can be written naturally in TOML.
+    pub fn matches(&self, actual: &str) -> bool {
+        self.to_string().trim_end() == actual.trim_end()
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
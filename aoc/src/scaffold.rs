@@ -0,0 +1,138 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Files the scaffold touched, relative to the workspace root.
+#[derive(Debug, Default)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub registered: Vec<PathBuf>,
+}
+
+/// Creates `dayNN` from the workspace's `template/` and registers it in the workspace members,
+/// the `aoc` crate's dependencies and `aoc/src/days.rs`.
+pub fn new(root: &Path, day: u8) -> Result<Scaffolded> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    let mut scaffolded = Scaffolded::default();
+    copy_template(&root.join("template"), &dir, &name, &mut scaffolded.created)
+        .context("copying template")?;
+    for path in &mut scaffolded.created {
+        *path = path.strip_prefix(root).unwrap_or(path).to_path_buf();
+    }
+
+    let edits: [(&str, Register); 3] = [
+        ("Cargo.toml", register_member),
+        ("aoc/Cargo.toml", register_dependency),
+        ("aoc/src/days.rs", register_day),
+    ];
+    for (file, edit) in edits {
+        let path = root.join(file);
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        if let Some(text) = edit(&text, &name, day) {
+            std::fs::write(&path, text).with_context(|| format!("writing {}", path.display()))?;
+            scaffolded.registered.push(file.into());
+        }
+    }
+    Ok(scaffolded)
+}
+
+/// Adds a day to a file's text, or `None` if it's already there.
+type Register = fn(&str, &str, u8) -> Option<String>;
+
+/// Fills in the cargo-generate placeholders the template uses.
+pub fn render(text: &str, name: &str) -> String {
+    let camel = name[..1].to_uppercase() + &name[1..];
+    text.replace("{{crate_name | upper_camel_case}}", &camel)
+        .replace("{{project-name}}", name)
+        .replace("{{crate_name}}", name)
+}
+
+fn copy_template(from: &Path, to: &Path, name: &str, created: &mut Vec<PathBuf>) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from).with_context(|| format!("reading {}", from.display()))? {
+        let entry = entry?;
+        let (src, dst) = (entry.path(), to.join(entry.file_name()));
+        if entry.file_type()?.is_dir() {
+            copy_template(&src, &dst, name, created)?;
+        } else {
+            let text = std::fs::read_to_string(&src)?;
+            std::fs::write(&dst, render(&text, name))?;
+            created.push(dst);
+        }
+    }
+    Ok(())
+}
+
+/// Inserts `line` after the last of `lines` that `after` accepts, unless `line` is already present.
+fn insert_after(text: &str, line: String, after: impl Fn(&str) -> bool) -> Option<String> {
+    insert_at(text, line, |lines| lines.iter().rposition(|l| after(l)))
+}
+
+/// Inserts `line` after the line `find` picks out, unless `line` is already present.
+fn insert_at(text: &str, line: String, find: impl Fn(&[&str]) -> Option<usize>) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.iter().any(|l| l.trim() == line.trim()) {
+        return None;
+    }
+    let at = find(&lines)?;
+    lines.insert(at + 1, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// A day crate's name from a line mentioning `dayNN`, if the line starts with it (after any
+/// indentation and quoting).
+fn day_name(line: &str) -> Option<&str> {
+    let line = line.trim_start().trim_start_matches('"');
+    let name = line.get(..5)?;
+    (name.starts_with("day") && name[3..].bytes().all(|b| b.is_ascii_digit())).then_some(name)
+}
+
+pub fn register_member(text: &str, name: &str, _: u8) -> Option<String> {
+    let indent = text
+        .lines()
+        .find(|l| day_name(l).is_some())
+        .map_or("\t", |l| &l[..l.len() - l.trim_start().len()]);
+    insert_after(text, format!("{}\"{}\",", indent, name), |l| {
+        day_name(l).is_some_and(|n| n < name)
+    })
+    .or_else(|| {
+        insert_after(text, format!("{}\"{}\",", indent, name), |l| {
+            l.trim_start().starts_with("members")
+        })
+    })
+}
+
+pub fn register_dependency(text: &str, name: &str, _: u8) -> Option<String> {
+    let line = format!("{} = {{ path = \"../{}\" }}", name, name);
+    insert_after(text, line.clone(), |l| {
+        day_name(l).is_some_and(|n| n < name)
+    })
+    .or_else(|| insert_after(text, line, |l| l.trim() == "[dependencies]"))
+}
+
+pub fn register_day(text: &str, name: &str, day: u8) -> Option<String> {
+    let camel = name[..1].to_uppercase() + &name[1..];
+    let line = format!(
+        "        Day::new::<{}::{}, {}::Data>({}),",
+        name, camel, name, day
+    );
+    insert_at(text, line.clone(), |lines| {
+        let at = lines.iter().rposition(|l| {
+            let Some(rest) = l.trim_start().strip_prefix("Day::new::<") else {
+                return false;
+            };
+            day_name(rest).is_some_and(|n| n < name)
+        })?;
+        // An entry continues over any builder calls on the lines after it.
+        let end = lines[at..]
+            .iter()
+            .position(|l| l.trim_end().ends_with(','))?;
+        Some(at + end)
+    })
+    .or_else(|| insert_after(text, line, |l| l.trim() == "vec!["))
+}
@@ -0,0 +1,56 @@
+use crate::all::{quietly, timed};
+use crate::bench::Stage;
+use crate::days::Day;
+use common::report::Outcome;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// A generated input that a day failed to parse or solve.
+pub struct Failure {
+    pub day: u8,
+    pub seed: u64,
+    pub stage: Stage,
+    pub outcome: Outcome,
+}
+
+/// Random input for `day`, reproducible from `seed`.
+pub fn generate(day: &Day, size: usize, seed: u64) -> String {
+    (day.generate)(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Solves `count` generated inputs of `size` for each day, seeded from `seed` upwards and
+/// wrapping past `u64::MAX` to 0, and collects every error or panic. Unsolved parts aren't
+/// failures.
+pub fn run(days: &[&Day], size: usize, count: u64, seed: u64) -> Vec<Failure> {
+    quietly(|| {
+        let mut failures = Vec::new();
+        for day in days {
+            for seed in (0..count).map(|i| seed.wrapping_add(i)) {
+                let data = generate(day, size, seed);
+                let fail = |stage, outcome| Failure {
+                    day: day.number,
+                    seed,
+                    stage,
+                    outcome,
+                };
+
+                let (_, input) = timed(|| Ok(day.solver.parse(&data)?));
+                let input = match input {
+                    Ok(input) => input,
+                    Err(outcome) => {
+                        failures.push(fail(Stage::Parse, outcome));
+                        continue;
+                    }
+                };
+                for (part, stage) in [(1, Stage::Part1), (2, Stage::Part2)] {
+                    let (_, answer) = timed(|| day.solver.part(part, input.as_ref()));
+                    match answer {
+                        Err(outcome) if outcome.failed() => failures.push(fail(stage, outcome)),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        failures
+    })
+}
@@ -0,0 +1,195 @@
+use crate::config::Config;
+use crate::fetch::Client;
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer; nothing was checked.
+    Wait,
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+    /// The response didn't look like any page we know.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the server checked the answer and rejected it.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "right"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "wrong (too high)"),
+            Self::TooLow => write!(f, "wrong (too low)"),
+            Self::Wait => write!(f, "too soon, please wait"),
+            Self::AlreadySolved => write!(f, "already solved"),
+            Self::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+lazy_static! {
+    static ref LEFT_TO_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    static ref PLEASE_WAIT: Regex =
+        Regex::new(r"(?i)wait (one|two|three|four|five|ten|\d+) minutes?").unwrap();
+}
+
+/// Reads the verdict out of the page returned for a submitted answer, along with how many seconds
+/// the server wants us to wait before the next one.
+pub fn parse_verdict(page: &str) -> (Verdict, Option<u64>) {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Right
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        Verdict::Unknown
+    };
+
+    let wait = if let Some(cap) = LEFT_TO_WAIT.captures(page) {
+        let minutes: u64 = cap
+            .get(1)
+            .map_or(Ok(0), |m| m.as_str().parse())
+            .unwrap_or(0);
+        let seconds: u64 = cap[2].parse().unwrap_or(0);
+        Some(minutes * 60 + seconds)
+    } else if let Some(cap) = PLEASE_WAIT.captures(page) {
+        let minutes = match cap[1].to_lowercase().as_str() {
+            "one" => 1,
+            "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "ten" => 10,
+            n => n.parse().unwrap_or(1),
+        };
+        Some(minutes * 60)
+    } else {
+        None
+    };
+
+    (verdict, wait)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time the answer was submitted.
+    pub at: u64,
+    /// Unix time before which the server won't accept another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+/// Every answer submitted for a day, kept in the day's `data/submissions.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Log {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl Log {
+    pub const FILE: &'static str = "submissions.toml";
+
+    /// Reads a log from disk, treating a missing file as empty.
+    pub fn open(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self)?;
+        std::fs::write(path, text).with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Refuses answers the log already says are wrong, parts that are already solved, and
+    /// submissions made before the server's wait has passed.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<()> {
+        if let Some(until) = self.submissions.iter().filter_map(|s| s.wait_until).max() {
+            if until > now {
+                bail!("server asked us to wait {}s more", until - now);
+            }
+        }
+
+        let number = answer.parse::<i64>().ok();
+        for s in self.submissions.iter().filter(|s| s.part == part) {
+            match s.verdict {
+                Verdict::Right | Verdict::AlreadySolved if s.answer == answer => {
+                    bail!("part {} was already solved with {}", part, answer)
+                }
+                Verdict::Right => bail!("part {} was already solved with {}", part, s.answer),
+                v if v.is_wrong() && s.answer == answer => {
+                    bail!("{} was already submitted and was {}", answer, v)
+                }
+                _ => {}
+            }
+
+            let (Some(number), Ok(bound)) = (number, s.answer.parse::<i64>()) else {
+                continue;
+            };
+            match s.verdict {
+                Verdict::TooHigh if number >= bound => {
+                    bail!("{} is wrong: {} was already too high", answer, bound)
+                }
+                Verdict::TooLow if number <= bound => {
+                    bail!("{} is wrong: {} was already too low", answer, bound)
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the day's log in `dir` rules it out, then records the verdict.
+pub fn submit(config: &Config, day: u8, part: u8, answer: &str, dir: &Path) -> Result<Submission> {
+    let path = dir.join(Log::FILE);
+    let mut log = Log::open(&path)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    log.check(part, answer, now)?;
+
+    let page = Client::from_config(config)?.answer(day, part, answer)?;
+    let (verdict, wait) = parse_verdict(&page);
+    let submission = Submission {
+        part,
+        answer: answer.to_string(),
+        verdict,
+        at: now,
+        wait_until: wait.map(|w| now + w),
+    };
+
+    log.submissions.push(submission.clone());
+    log.save(&path)?;
+    Ok(submission)
+}
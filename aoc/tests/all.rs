@@ -0,0 +1,105 @@
+use anyhow::{bail, Result};
+use aoc::all::run;
+use aoc::bench;
+use aoc::days::Day;
+use common::report::{render, Format, Outcome};
+use common::{ParseError, Solution};
+
+struct Fine;
+
+impl Solution for Fine {
+    type Input = u32;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<u32, ParseError> {
+        Ok(data.len() as u32)
+    }
+
+    fn part1(n: &u32) -> Result<u32> {
+        Ok(n + 1)
+    }
+
+    fn part2(n: &u32) -> Result<u32> {
+        Ok(n * 2)
+    }
+}
+
+struct Broken;
+
+impl Solution for Broken {
+    type Input = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(_: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &()) -> Result<u32> {
+        panic!("part 1 exploded")
+    }
+
+    fn part2(_: &()) -> Result<u32> {
+        bail!("part 2 failed")
+    }
+}
+
+fn day<S: Solution + 'static>(number: u8) -> Day {
+    Day {
+        number,
+        read: |_| Ok("abc".into()),
+        open: |_| Ok(Box::new("abc".as_bytes())),
+        exists: |_| true,
+        solver: common::solver::<S>(),
+        generate: |_, _| String::new(),
+        animate: None,
+        draw: None,
+        stream: None,
+    }
+}
+
+#[test]
+fn isolates_panics_and_errors_per_stage() {
+    let days = [day::<Broken>(1), day::<Fine>(2)];
+    let report = run(&days.iter().collect::<Vec<_>>(), &[1, 2], Some(2)).unwrap();
+
+    let outcome = |day: u8, part: u8| {
+        &report.reports[day as usize - 1]
+            .parts
+            .iter()
+            .find(|p| p.part == part)
+            .unwrap()
+            .outcome
+    };
+    assert!(matches!(outcome(1, 1), Outcome::Panicked { message } if message == "part 1 exploded"));
+    assert!(matches!(outcome(1, 2), Outcome::Error { message } if message == "part 2 failed"));
+    assert!(matches!(outcome(2, 1), Outcome::Solved { answer } if answer == "4"));
+    assert!(matches!(outcome(2, 2), Outcome::Solved { answer } if answer == "6"));
+    assert_eq!(report.failures(), 1);
+
+    let tsv = render(&report.reports, Format::Tsv);
+    assert!(tsv.contains("\n1\tpart1\tpanicked\t"), "{}", tsv);
+    assert!(tsv.contains("\tpart 1 exploded\n"), "{}", tsv);
+}
+
+#[test]
+fn days_without_input_are_skipped_rather_than_failed() {
+    let missing = Day {
+        exists: |_| false,
+        read: |_| bail!("file not found: input.txt"),
+        ..day::<Fine>(3)
+    };
+    let days = [missing, day::<Fine>(2)];
+    let days: Vec<_> = days.iter().collect();
+
+    let report = run(&days, &[1, 2], Some(2)).unwrap();
+    let skipped = report.reports.iter().find(|r| r.day == 3).unwrap();
+    assert!(matches!(skipped.stopped, Some(Outcome::Skipped)));
+    assert!(skipped.parts.is_empty());
+    assert_eq!(report.failures(), 0);
+
+    let report = bench::run(&days, 1).unwrap();
+    assert_eq!(report.skipped, [3]);
+    assert!(report.timings.iter().all(|t| t.day == 2));
+}
@@ -0,0 +1,48 @@
+use aoc::alloc::{self, Usage};
+use std::hint::black_box;
+
+// With `count-alloc` the library installs the counting allocator itself.
+#[cfg(not(feature = "count-alloc"))]
+#[global_allocator]
+static GLOBAL: alloc::Counting = alloc::Counting;
+
+/// The counters are global, so everything is measured from this one test.
+#[test]
+fn counts_allocations_bytes_and_peak() {
+    let (_, usage) = alloc::measure(|| black_box(Vec::<u8>::with_capacity(1000)));
+    let expected = Usage {
+        allocations: 1,
+        bytes: 1000,
+        peak: 1000,
+    };
+    assert_eq!(usage, expected);
+
+    // Freed memory doesn't count towards the peak again.
+    let (_, usage) = alloc::measure(|| {
+        drop(black_box(vec![1u8; 1000]));
+        black_box(vec![2u8; 600]).len()
+    });
+    let expected = Usage {
+        allocations: 2,
+        bytes: 1600,
+        peak: 1000,
+    };
+    assert_eq!(usage, expected);
+
+    // Growing counts as another allocation of the difference.
+    let (_, usage) = alloc::measure(|| {
+        let mut v = black_box(Vec::<u8>::with_capacity(100));
+        v.reserve_exact(300);
+        black_box(v)
+    });
+    let expected = Usage {
+        allocations: 2,
+        bytes: 300,
+        peak: 300,
+    };
+    assert_eq!(usage, expected);
+
+    assert_eq!(alloc::bytes(512), "512B");
+    assert_eq!(alloc::bytes(1536), "1.5KiB");
+    assert_eq!(alloc::bytes(3 << 20), "3.0MiB");
+}
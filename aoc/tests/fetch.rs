@@ -0,0 +1,64 @@
+mod support;
+
+use aoc::config::Config;
+use aoc::fetch::{fetch, Fetched};
+use support::{temp_dir, Server};
+
+fn config(server: &Server) -> Config {
+    Config {
+        session: Some("abc123".into()),
+        base_url: Some(server.url.clone()),
+    }
+}
+
+#[test]
+fn downloads_input_with_session_cookie() {
+    let server = Server::start(|_| (200, "1000\n2000\n".into()));
+    let dir = temp_dir("fetch-download");
+
+    let config = config(&server);
+    let fetched = fetch(&config, 1, &dir).unwrap();
+
+    assert!(matches!(fetched, Fetched::Downloaded(_)));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("input.txt")).unwrap(),
+        "1000\n2000\n"
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+}
+
+#[test]
+fn never_refetches_cached_input() {
+    let server = Server::start(|_| (200, "fresh\n".into()));
+    let dir = temp_dir("fetch-cached");
+    std::fs::write(dir.join("input.txt"), "cached\n").unwrap();
+
+    let config = config(&server);
+    for _ in 0..2 {
+        let fetched = fetch(&config, 7, &dir).unwrap();
+        assert!(matches!(fetched, Fetched::Cached(_)));
+    }
+
+    assert!(server.requests().is_empty());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("input.txt")).unwrap(),
+        "cached\n"
+    );
+}
+
+#[test]
+fn reports_unavailable_day_without_writing() {
+    let server = Server::start(|_| (404, "Not Found".into()));
+    let dir = temp_dir("fetch-missing");
+
+    let config = config(&server);
+    let err = fetch(&config, 25, &dir).err().unwrap();
+
+    assert!(err.to_string().contains("isn't available yet"), "{}", err);
+    assert!(!dir.join("input.txt").exists());
+}
@@ -0,0 +1,106 @@
+mod support;
+
+use aoc::scaffold::{new, register_day, register_dependency, register_member};
+use std::path::Path;
+use std::process::Command;
+use support::temp_dir;
+
+const MEMBERS: &str = "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"day01\",\n\t\"day03\",\n]\n";
+const DEPENDENCIES: &str = "[dependencies]\nanyhow = \"1.0.66\"\nday01 = { path = \"../day01\" }\n";
+const DAYS: &str = "pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01, day01::Data>(1)
+            .animated::<day01::Day01>(),
+    ]
+}
+";
+
+#[test]
+fn registers_in_order_and_only_once() {
+    let members = register_member(MEMBERS, "day02", 2).unwrap();
+    assert_eq!(
+        members,
+        "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"day01\",\n\t\"day02\",\n\t\"day03\",\n]\n"
+    );
+    assert_eq!(register_member(&members, "day02", 2), None);
+
+    let deps = register_dependency(DEPENDENCIES, "day02", 2).unwrap();
+    assert!(deps.ends_with("day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n"));
+    assert_eq!(register_dependency(&deps, "day02", 2), None);
+
+    let days = register_day(DAYS, "day02", 2).unwrap();
+    assert!(days.contains("Day01>(),\n        Day::new::<day02::Day02, day02::Data>(2),\n    ]"));
+    assert_eq!(register_day(&days, "day02", 2), None);
+}
+
+/// A workspace holding the real `common` crate beside an `aoc` crate to register days with.
+const WORKSPACE: &str = "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"common\",\n]\n";
+const AOC: &str =
+    "[package]\nname = \"aoc\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n";
+
+#[test]
+fn creates_day_from_template() {
+    let root = temp_dir("scaffold");
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    copy_dir(&workspace.join("template"), &root.join("template"));
+    copy_dir(&workspace.join("common/src"), &root.join("common/src"));
+    std::fs::copy(
+        workspace.join("common/Cargo.toml"),
+        root.join("common/Cargo.toml"),
+    )
+    .unwrap();
+    std::fs::copy(workspace.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+    std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+    std::fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+    std::fs::write(root.join("aoc/Cargo.toml"), AOC).unwrap();
+    std::fs::write(root.join("aoc/src/lib.rs"), "").unwrap();
+    std::fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+
+    let scaffolded = new(&root, 7).unwrap();
+    assert_eq!(scaffolded.registered.len(), 3);
+
+    let lib = std::fs::read_to_string(root.join("day07/src/lib.rs")).unwrap();
+    assert!(lib.contains("impl Solution for Day07 {"));
+    for path in &scaffolded.created {
+        let text = std::fs::read_to_string(root.join(path)).unwrap();
+        assert!(
+            !text.contains("{{"),
+            "{} has unrendered placeholders",
+            path.display()
+        );
+    }
+    assert!(root.join("day07/data/answers.toml").exists());
+
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "build",
+            "--offline",
+            "--quiet",
+            "--all-targets",
+            "-p",
+            "day07",
+        ])
+        .current_dir(&root)
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold"),
+        )
+        .status()
+        .unwrap();
+    assert!(status.success(), "the scaffolded day doesn't build");
+
+    let err = new(&root, 7).unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()));
+        } else {
+            std::fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+        }
+    }
+}
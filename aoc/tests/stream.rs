@@ -0,0 +1,24 @@
+use aoc::days;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn streaming_matches_parsing_whole_input() {
+    let days = days::all();
+    let streamed: Vec<_> = days.iter().filter(|d| d.stream.is_some()).collect();
+    assert_eq!(streamed.len(), 6);
+
+    for day in streamed {
+        for seed in 0..5 {
+            let data = (day.generate)(&mut StdRng::seed_from_u64(seed), 50);
+            let input = day.solver.parse(&data).unwrap();
+            let expected = (
+                day.solver.part1(input.as_ref()).unwrap(),
+                day.solver.part2(input.as_ref()).unwrap(),
+            );
+            let stream = day.stream.unwrap();
+            let answers = stream(&mut data.as_bytes()).unwrap();
+            assert_eq!(answers, expected, "day {} seed {}", day.number, seed);
+        }
+    }
+}
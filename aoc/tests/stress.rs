@@ -0,0 +1,44 @@
+use anyhow::Result;
+use aoc::bench::Stage;
+use aoc::days::Day;
+use aoc::stress;
+use common::{ParseError, Solution};
+
+struct Rejects;
+
+impl Solution for Rejects {
+    type Input = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<(), ParseError> {
+        Err(ParseError::at(data, data, "never parses"))
+    }
+
+    fn part1(_: &()) -> Result<u32> {
+        Ok(0)
+    }
+
+    fn part2(_: &()) -> Result<u32> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn seeds_wrap_past_the_largest() {
+    let day = Day {
+        number: 1,
+        read: |_| Ok(String::new()),
+        open: |_| Ok(Box::new("".as_bytes())),
+        exists: |_| true,
+        solver: common::solver::<Rejects>(),
+        generate: |_, _| String::new(),
+        animate: None,
+        draw: None,
+        stream: None,
+    };
+    let failures = stress::run(&[&day], 10, 3, u64::MAX - 1);
+    let seeds: Vec<u64> = failures.iter().map(|f| f.seed).collect();
+    assert_eq!(seeds, [u64::MAX - 1, u64::MAX, 0]);
+    assert!(failures.iter().all(|f| f.stage == Stage::Parse));
+}
@@ -0,0 +1,100 @@
+mod support;
+
+use aoc::config::Config;
+use aoc::submit::{parse_verdict, submit, Log, Verdict};
+use support::{temp_dir, Server};
+
+const RIGHT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+    Please wait one minute before trying again.</p></article>";
+const TOO_RECENT: &str =
+    "<article><p>You gave an answer too recently. You have 1m 23s left to wait.</p></article>";
+
+fn config(server: &Server) -> Config {
+    Config {
+        session: Some("abc123".into()),
+        base_url: Some(server.url.clone()),
+    }
+}
+
+#[test]
+fn parses_verdicts_and_waits() {
+    assert_eq!(parse_verdict(RIGHT), (Verdict::Right, None));
+    assert_eq!(parse_verdict(TOO_HIGH), (Verdict::TooHigh, Some(60)));
+    assert_eq!(parse_verdict(TOO_RECENT), (Verdict::Wait, Some(83)));
+    assert_eq!(
+        parse_verdict("That's not the right answer; your answer is too low. please wait 5 minutes"),
+        (Verdict::TooLow, Some(300))
+    );
+    assert_eq!(
+        parse_verdict("You don't seem to be solving the right level."),
+        (Verdict::AlreadySolved, None)
+    );
+    assert_eq!(parse_verdict("<html></html>"), (Verdict::Unknown, None));
+}
+
+#[test]
+fn posts_answer_and_records_verdict() {
+    let server = Server::start(|_| (200, RIGHT.into()));
+    let dir = temp_dir("submit-right");
+
+    let submission = submit(&config(&server), 1, 2, "45000", &dir).unwrap();
+    assert_eq!(submission.verdict, Verdict::Right);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+    assert_eq!(requests[0].body, "level=2&answer=45000");
+
+    let log = Log::open(&dir.join(Log::FILE)).unwrap();
+    assert_eq!(log.submissions.len(), 1);
+    assert_eq!(log.submissions[0].answer, "45000");
+    assert_eq!(log.submissions[0].verdict, Verdict::Right);
+
+    let err = submit(&config(&server), 1, 2, "45001", &dir).unwrap_err();
+    assert!(err.to_string().contains("already solved"), "{}", err);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn never_resubmits_known_wrong_answers() {
+    let server = Server::start(|_| (200, TOO_HIGH.into()));
+    let dir = temp_dir("submit-wrong");
+    let config = config(&server);
+
+    let submission = submit(&config, 1, 1, "500", &dir).unwrap();
+    assert_eq!(submission.verdict, Verdict::TooHigh);
+
+    // Clear the wait so only the known-wrong check can refuse.
+    let path = dir.join(Log::FILE);
+    let mut log = Log::open(&path).unwrap();
+    log.submissions[0].wait_until = None;
+    log.save(&path).unwrap();
+
+    for answer in ["500", "600"] {
+        let err = submit(&config, 1, 1, answer, &dir).unwrap_err();
+        assert!(err.to_string().contains("too high"), "{}", err);
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    submit(&config, 1, 1, "400", &dir).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn waits_out_throttle_before_submitting() {
+    let server = Server::start(|_| (200, TOO_RECENT.into()));
+    let dir = temp_dir("submit-throttle");
+    let config = config(&server);
+
+    let submission = submit(&config, 3, 1, "17", &dir).unwrap();
+    assert_eq!(submission.verdict, Verdict::Wait);
+    assert_eq!(submission.wait_until, Some(submission.at + 83));
+
+    let err = submit(&config, 3, 1, "18", &dir).unwrap_err();
+    assert!(err.to_string().contains("wait"), "{}", err);
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,8 @@
+use rand::rngs::StdRng;
+
+/// Produces random, valid puzzle input for stress and property testing.
+pub trait Generate {
+    /// Input of roughly `size` units: elves, rounds, moves, grid width and so on, as each day
+    /// documents. Days clamp `size` to whatever the puzzle needs to stay solvable.
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+}
@@ -0,0 +1,92 @@
+use anyhow::{Context, Result};
+use rust_embed::RustEmbed;
+use std::convert::Infallible;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file compiled in from the day's `data/` directory.
+    Embedded(String),
+    /// A file on disk.
+    Path(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Self::Embedded("input.txt".into())
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    /// Interprets a command-line value, where `-` means stdin.
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        Ok(match v {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        })
+    }
+}
+
+impl Source {
+    /// Name of the input for messages, relative to the day's crate when embedded.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Embedded(name) => format!("data/{}", name),
+            Self::Path(path) => path.display().to_string(),
+            Self::Stdin => "<stdin>".into(),
+        }
+    }
+
+    /// Whether the input is there to read, looking up embedded files in `E`.
+    pub fn exists<E: RustEmbed>(&self) -> bool {
+        match self {
+            Self::Embedded(name) => E::get(name).is_some(),
+            Self::Path(path) => path.exists(),
+            Self::Stdin => true,
+        }
+    }
+
+    /// Opens the input for reading a piece at a time, looking up embedded files in `E`.
+    pub fn open<E: RustEmbed>(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Self::Embedded(name) => {
+                let file = E::get(name).with_context(|| format!("file not found: {}", name))?;
+                Ok(Box::new(Cursor::new(file.data)))
+            }
+            Self::Path(path) => {
+                let file =
+                    File::open(path).with_context(|| format!("reading {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Self::Stdin => Ok(Box::new(std::io::stdin().lock())),
+        }
+    }
+
+    /// Reads the input as text, looking up embedded files in `E`.
+    pub fn read<E: RustEmbed>(&self) -> Result<String> {
+        match self {
+            Self::Embedded(name) => {
+                let file = E::get(name).with_context(|| format!("file not found: {}", name))?;
+                Ok(std::str::from_utf8(file.data.as_ref())?.to_string())
+            }
+            Self::Path(path) => {
+                std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+            }
+            Self::Stdin => {
+                let mut data = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut data)
+                    .context("reading stdin")?;
+                Ok(data)
+            }
+        }
+    }
+}
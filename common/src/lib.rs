@@ -0,0 +1,13 @@
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod solution;
+#[cfg(feature = "test-support")]
+pub mod testing;
+
+pub use generate::Generate;
+pub use input::Source;
+pub use parse::ParseError;
+pub use report::Report;
+pub use solution::{solver, Solution, Solver, Stream, Unsolved};
@@ -0,0 +1,110 @@
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Puzzle input that couldn't be parsed, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Where the input came from, once the caller knows.
+    pub file: Option<String>,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, in characters, of the offending text.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `text`, a slice of `input`, located by where it sits in `input`.
+    pub fn at(input: &str, text: &str, message: impl Display) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o <= input.len() && input.is_char_boundary(o))
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error about something missing from the end of `input`.
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Moves an error found in a single line of input, parsed on its own, to that line's
+    /// 1-based number.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+
+    /// Names the file the input was read from.
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reads `reader` a line at a time, giving each line's 1-based number and its text without the
+/// line ending, so only one line is held in memory at once. A line that can't be read, such as
+/// one that isn't UTF-8, is reported at that line and ends the input.
+pub fn lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String), ParseError>> {
+    let mut failed = false;
+    reader.lines().enumerate().map_while(move |(i, line)| {
+        if failed {
+            return None;
+        }
+        failed = line.is_err();
+        Some(line.map(|line| (i + 1, line)).map_err(|err| ParseError {
+            file: None,
+            line: i + 1,
+            column: 1,
+            text: String::new(),
+            message: format!("reading input: {}", err),
+        }))
+    })
+}
+
+/// Parses `text`, a slice of `input`, reporting failures at its location.
+pub fn parse_at<T>(input: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|err| ParseError::at(input, text, err))
+}
+
+/// Splits `text`, a slice of `input`, around the first `sep`, reporting a missing `sep` at the
+/// location of `text`.
+pub fn split_at<'a>(
+    input: &str,
+    text: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(sep)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {:?}", sep)))
+}
@@ -0,0 +1,340 @@
+use crate::{ParseError, Solution, Solver, Source, Unsolved};
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use rust_embed::RustEmbed;
+use serde::Serialize;
+use std::any::Any;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::time::Instant;
+
+/// A day's answers and how long each stage took, rendered the same way by every day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    /// Time to parse the input, unless it was solved while being read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    /// Time for everything, parsing included.
+    pub total_ns: u64,
+    /// Why no part ran: the input was missing, or failed or panicked while being parsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopped: Option<Outcome>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    /// Time to solve this part, unless it was solved together with the others.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ns: Option<u64>,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// How a part turned out.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Outcome {
+    Solved {
+        answer: String,
+    },
+    Unsolved,
+    /// There was no input to run on.
+    Skipped,
+    Error {
+        message: String,
+    },
+    Panicked {
+        message: String,
+    },
+}
+
+impl Outcome {
+    /// The outcome of an answer, or of the error it failed with.
+    pub fn of(result: Result<String>) -> Self {
+        match result {
+            Ok(answer) => Self::Solved { answer },
+            Err(err) => Self::error(err),
+        }
+    }
+
+    /// The outcome of an error, where [`Unsolved`] isn't a failure.
+    pub fn error(err: anyhow::Error) -> Self {
+        match err.is::<Unsolved>() {
+            true => Self::Unsolved,
+            false => Self::Error {
+                message: format!("{:#}", err),
+            },
+        }
+    }
+
+    /// Whether this is an error or a panic, rather than an answer or a part left alone.
+    pub fn failed(&self) -> bool {
+        matches!(self, Self::Error { .. } | Self::Panicked { .. })
+    }
+
+    /// How the outcome reads in a report, with answers as they are.
+    fn describe(&self) -> String {
+        match self {
+            Self::Solved { answer } => answer.clone(),
+            Self::Unsolved => "(not solved)".into(),
+            Self::Skipped => "(no input.txt)".into(),
+            Self::Error { message } => format!("error: {}", message),
+            Self::Panicked { message } => format!("panicked: {}", message),
+        }
+    }
+}
+
+impl Report {
+    /// A report for `parts` of input that took `parse_ns` to parse.
+    pub fn new(day: u8, parse_ns: u64, parts: Vec<PartReport>) -> Self {
+        let total_ns = parse_ns + parts.iter().filter_map(|p| p.ns).sum::<u64>();
+        Self {
+            day,
+            parse_ns: Some(parse_ns),
+            total_ns,
+            stopped: None,
+            parts,
+        }
+    }
+
+    /// A report for a day whose input never reached its parts, after `parse_ns` trying to parse
+    /// it if it was there.
+    pub fn stopped(day: u8, parse_ns: Option<u64>, outcome: Outcome) -> Self {
+        Self {
+            day,
+            parse_ns,
+            total_ns: parse_ns.unwrap_or_default(),
+            stopped: Some(outcome),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Parses `data` and solves `parts` of it, timing each stage.
+    pub fn solve(
+        day: u8,
+        solver: &dyn Solver,
+        data: &str,
+        parts: &[u8],
+    ) -> Result<Self, ParseError> {
+        let start = Instant::now();
+        let input = solver.parse(data)?;
+        let parse_ns = start.elapsed().as_nanos() as u64;
+        Ok(Self::solve_parsed(
+            day,
+            parse_ns,
+            solver,
+            input.as_ref(),
+            parts,
+        ))
+    }
+
+    /// Solves `parts` of input that took `parse_ns` to parse, timing each part.
+    pub fn solve_parsed(
+        day: u8,
+        parse_ns: u64,
+        solver: &dyn Solver,
+        input: &dyn Any,
+        parts: &[u8],
+    ) -> Self {
+        let parts: Vec<PartReport> = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let outcome = Outcome::of(solver.part(part, input));
+                PartReport {
+                    part,
+                    ns: Some(start.elapsed().as_nanos() as u64),
+                    outcome,
+                }
+            })
+            .collect();
+        Self::new(day, parse_ns, parts)
+    }
+
+    /// A report for `parts` of answers found in one pass that took `total_ns`, with no time of
+    /// their own.
+    pub fn streamed(day: u8, total_ns: u64, answers: [String; 2], parts: &[u8]) -> Self {
+        let parts = (1..=2)
+            .zip(answers)
+            .filter(|(part, _)| parts.contains(part))
+            .map(|(part, answer)| PartReport {
+                part,
+                ns: None,
+                outcome: Outcome::Solved { answer },
+            })
+            .collect();
+        Self {
+            day,
+            parse_ns: None,
+            total_ns,
+            stopped: None,
+            parts,
+        }
+    }
+
+    /// Whether parsing or any part failed, rather than being solved, skipped or left unsolved.
+    pub fn failed(&self) -> bool {
+        self.stopped.iter().any(Outcome::failed) || self.parts.iter().any(|p| p.outcome.failed())
+    }
+}
+
+/// How reports are written out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Indented answers with timings, for reading.
+    #[default]
+    Text,
+    /// An array with one object per day.
+    Json,
+    /// One row per stage under a header, with tabs, newlines and backslashes in answers escaped.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(v: &str) -> Result<Self> {
+        match v {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            v => bail!("unknown format {:?}, expected text, json or tsv", v),
+        }
+    }
+}
+
+/// Renders `reports` in `format`.
+pub fn render(reports: &[Report], format: Format) -> String {
+    match format {
+        Format::Text => reports.iter().map(text).collect(),
+        Format::Json => serde_json::to_string_pretty(reports).unwrap_or_default() + "\n",
+        Format::Tsv => tsv(reports),
+    }
+}
+
+fn text(report: &Report) -> String {
+    let time = |ns: Option<u64>| ns.map(|ns| format!(" ({})", duration(ns)));
+    let mut out = format!("Day {:02}", report.day);
+    if let Some(outcome) = &report.stopped {
+        let time = time(report.parse_ns).unwrap_or_default();
+        let _ = writeln!(out, "\n  Parse: {}{}", outcome.describe(), time);
+        return out;
+    }
+    let _ = match report.parse_ns {
+        Some(ns) => writeln!(out, " (parsed in {})", duration(ns)),
+        None => writeln!(out, " (streamed in {})", duration(report.total_ns)),
+    };
+    for p in &report.parts {
+        let time = time(p.ns).unwrap_or_default();
+        let _ = match &p.outcome {
+            Outcome::Solved { answer } if answer.contains('\n') => {
+                let _ = writeln!(out, "  Part {}{}:", p.part, time);
+                answer
+                    .lines()
+                    .try_for_each(|line| writeln!(out, "    {}", line))
+            }
+            Outcome::Unsolved | Outcome::Skipped => {
+                writeln!(out, "  Part {}: {}", p.part, p.outcome.describe())
+            }
+            outcome => writeln!(out, "  Part {}: {}{}", p.part, outcome.describe(), time),
+        };
+    }
+    out
+}
+
+fn tsv(reports: &[Report]) -> String {
+    let mut out = String::from("day\tstage\tstatus\tns\tanswer\n");
+    let ns = |ns: Option<u64>| ns.map(|ns| ns.to_string()).unwrap_or_default();
+    for r in reports {
+        let _ = match (&r.stopped, r.parse_ns) {
+            (Some(outcome), parse) => {
+                let (status, message) = fields(outcome);
+                let (ns, message) = (ns(parse), escape(message));
+                writeln!(out, "{}\tparse\t{}\t{}\t{}", r.day, status, ns, message)
+            }
+            (None, Some(parse)) => writeln!(out, "{}\tparse\tok\t{}\t", r.day, parse),
+            (None, None) => writeln!(out, "{}\tstream\tok\t{}\t", r.day, r.total_ns),
+        };
+        for p in &r.parts {
+            let (status, answer) = fields(&p.outcome);
+            let _ = writeln!(
+                out,
+                "{}\tpart{}\t{}\t{}\t{}",
+                r.day,
+                p.part,
+                status,
+                ns(p.ns),
+                escape(answer)
+            );
+        }
+    }
+    out
+}
+
+/// The status column of an outcome, and its answer or message.
+fn fields(outcome: &Outcome) -> (&str, &str) {
+    match outcome {
+        Outcome::Solved { answer } => ("solved", answer),
+        Outcome::Unsolved => ("unsolved", ""),
+        Outcome::Skipped => ("skipped", ""),
+        Outcome::Error { message } => ("error", message),
+        Outcome::Panicked { message } => ("panicked", message),
+    }
+}
+
+fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Formats nanoseconds with a unit suited to their magnitude.
+pub fn duration(ns: u64) -> String {
+    match ns {
+        ns if ns < 1_000 => format!("{}ns", ns),
+        ns if ns < 1_000_000 => format!("{:.1}µs", ns as f64 / 1e3),
+        ns if ns < 1_000_000_000 => format!("{:.1}ms", ns as f64 / 1e6),
+        ns => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+/// A day binary's command line.
+#[derive(Debug, Default, Parser)]
+#[command(about = "Solve both parts of a day's puzzle input")]
+pub struct Args {
+    /// Read input from this file, or `-` for stdin, instead of the embedded `input.txt`.
+    #[arg(long)]
+    pub input: Option<Source>,
+
+    /// How to print the answers: text, json or tsv.
+    #[arg(long, default_value = "text")]
+    pub format: Format,
+}
+
+/// Runs a day binary named `dayNN` with the process arguments.
+pub fn main<S: Solution + 'static, E: RustEmbed>(name: &str) -> Result<()> {
+    run::<S, E>(name, &Args::parse())
+}
+
+/// Solves both parts of the input `args` name for the day binary `name` and prints the report in
+/// the format asked for. Fails if the input doesn't parse or either part fails.
+pub fn run<S: Solution + 'static, E: RustEmbed>(name: &str, args: &Args) -> Result<()> {
+    let day = name
+        .trim_start_matches("day")
+        .parse()
+        .with_context(|| format!("{} isn't named dayNN", name))?;
+    let source = args.input.clone().unwrap_or_default();
+    let data = source.read::<E>()?;
+    let report = Report::solve(day, crate::solver::<S>().as_ref(), &data, &[1, 2])
+        .map_err(|err| err.in_file(source.name()))?;
+    print!("{}", render(std::slice::from_ref(&report), args.format));
+    if report.failed() {
+        bail!("day {} failed", day);
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::ParseError;
+use anyhow::{Context, Result};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+/// A day's puzzle: input is parsed once and shared by both parts.
+pub trait Solution {
+    /// Parsed puzzle input, shareable between threads so both parts can run at once.
+    type Input: Send + Sync + 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// A day that can solve both parts in a single pass over its input, holding only a bounded
+/// amount of it in memory, for inputs too large to read into a string.
+pub trait Stream: Solution {
+    fn stream(reader: impl BufRead) -> Result<(Self::Part1, Self::Part2)>;
+}
+
+/// Object-safe form of [`Solution`] with answers rendered as text, so tooling can run every day
+/// the same way.
+pub trait Solver: Send + Sync {
+    fn parse(&self, data: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError>;
+    fn part1(&self, input: &dyn Any) -> Result<String>;
+    fn part2(&self, input: &dyn Any) -> Result<String>;
+
+    fn part(&self, n: u8, input: &dyn Any) -> Result<String> {
+        match n {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            n => anyhow::bail!("no part {}", n),
+        }
+    }
+}
+
+/// Wraps `S` as a [`Solver`].
+pub fn solver<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Erased<S> {
+    fn input(input: &dyn Any) -> Result<&S::Input> {
+        input
+            .downcast_ref()
+            .context("input from a different solution")
+    }
+}
+
+impl<S: Solution> Solver for Erased<S> {
+    fn parse(&self, data: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError> {
+        Ok(Box::new(S::parse(data)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String> {
+        Ok(S::part1(Self::input(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String> {
+        Ok(S::part2(Self::input(input)?)?.to_string())
+    }
+}
+
+/// Error for a part that hasn't been solved yet.
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved")
+    }
+}
+
+impl std::error::Error for Unsolved {}
@@ -0,0 +1,12 @@
+//! Helpers for the days' tests, behind the `test-support` feature.
+
+use crate::Solution;
+
+/// Where `S` failed to parse `data`: line, column and the offending text. Panics if `data`
+/// parses.
+pub fn parse_error<S: Solution>(data: &str) -> (usize, usize, String) {
+    let Err(err) = S::parse(data) else {
+        panic!("input should be rejected");
+    };
+    (err.line, err.column, err.text)
+}
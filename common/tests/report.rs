@@ -0,0 +1,167 @@
+use common::report::{duration, render, Format, Outcome, PartReport, Report};
+use serde_json::json;
+
+fn report() -> Report {
+    Report {
+        day: 10,
+        parse_ns: Some(1_500),
+        total_ns: 2_000_000,
+        stopped: None,
+        parts: vec![
+            PartReport {
+                part: 1,
+                ns: Some(250),
+                outcome: Outcome::Solved {
+                    answer: "13140".into(),
+                },
+            },
+            PartReport {
+                part: 2,
+                ns: Some(12_000),
+                outcome: Outcome::Solved {
+                    answer: "##..\n#..#".into(),
+                },
+            },
+        ],
+    }
+}
+
+fn streamed() -> Report {
+    Report {
+        day: 1,
+        parse_ns: None,
+        total_ns: 3_000_000_000,
+        stopped: None,
+        parts: vec![
+            PartReport {
+                part: 1,
+                ns: None,
+                outcome: Outcome::Error {
+                    message: "a\tb\\c\nd".into(),
+                },
+            },
+            PartReport {
+                part: 2,
+                ns: None,
+                outcome: Outcome::Unsolved,
+            },
+        ],
+    }
+}
+
+#[test]
+fn text_indents_multi_line_answers_under_their_part() {
+    assert_eq!(
+        render(&[report()], Format::Text),
+        "Day 10 (parsed in 1.5µs)\n\
+         \x20 Part 1: 13140 (250ns)\n\
+         \x20 Part 2 (12.0µs):\n\
+         \x20   ##..\n\
+         \x20   #..#\n"
+    );
+    assert_eq!(
+        render(&[streamed()], Format::Text),
+        "Day 01 (streamed in 3.00s)\n\
+         \x20 Part 1: error: a\tb\\c\nd\n\
+         \x20 Part 2: (not solved)\n"
+    );
+}
+
+#[test]
+fn tsv_escapes_tabs_newlines_and_backslashes() {
+    let tsv = render(&[report(), streamed()], Format::Tsv);
+    assert_eq!(
+        tsv,
+        "day\tstage\tstatus\tns\tanswer\n\
+         10\tparse\tok\t1500\t\n\
+         10\tpart1\tsolved\t250\t13140\n\
+         10\tpart2\tsolved\t12000\t##..\\n#..#\n\
+         1\tstream\tok\t3000000000\t\n\
+         1\tpart1\terror\t\ta\\tb\\\\c\\nd\n\
+         1\tpart2\tunsolved\t\t\n"
+    );
+    assert!(tsv.lines().all(|l| l.split('\t').count() == 5));
+}
+
+#[test]
+fn json_tags_each_part_with_its_status() {
+    let json: serde_json::Value =
+        serde_json::from_str(&render(&[report(), streamed()], Format::Json)).unwrap();
+    assert_eq!(
+        json,
+        json!([
+            {
+                "day": 10,
+                "parse_ns": 1500,
+                "total_ns": 2000000,
+                "parts": [
+                    { "part": 1, "ns": 250, "status": "solved", "answer": "13140" },
+                    { "part": 2, "ns": 12000, "status": "solved", "answer": "##..\n#..#" },
+                ],
+            },
+            {
+                "day": 1,
+                "total_ns": 3000000000u64,
+                "parts": [
+                    { "part": 1, "status": "error", "message": "a\tb\\c\nd" },
+                    { "part": 2, "status": "unsolved" },
+                ],
+            },
+        ])
+    );
+}
+
+#[test]
+fn only_errors_count_as_failures() {
+    assert!(!report().failed());
+    assert!(streamed().failed());
+}
+
+#[test]
+fn formats_and_durations() {
+    assert_eq!("tsv".parse::<Format>().unwrap(), Format::Tsv);
+    assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+    assert!("csv".parse::<Format>().is_err());
+    assert_eq!(duration(999), "999ns");
+    assert_eq!(duration(1_000), "1.0µs");
+    assert_eq!(duration(2_500_000), "2.5ms");
+    assert_eq!(duration(1_234_000_000), "1.23s");
+}
+
+#[test]
+fn days_that_never_reached_their_parts() {
+    let reports = [
+        Report::stopped(
+            7,
+            Some(900),
+            Outcome::Panicked {
+                message: "boom".into(),
+            },
+        ),
+        Report::stopped(15, None, Outcome::Skipped),
+    ];
+    assert!(reports[0].failed());
+    assert!(!reports[1].failed());
+    assert_eq!(
+        render(&reports, Format::Text),
+        "Day 07\n  Parse: panicked: boom (900ns)\nDay 15\n  Parse: (no input.txt)\n"
+    );
+    assert_eq!(
+        render(&reports, Format::Tsv),
+        "day\tstage\tstatus\tns\tanswer\n\
+         7\tparse\tpanicked\t900\tboom\n\
+         15\tparse\tskipped\t\t\n"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&render(&reports[..1], Format::Json)).unwrap();
+    assert_eq!(
+        json,
+        json!([{
+            "day": 7,
+            "parse_ns": 900,
+            "total_ns": 900,
+            "stopped": { "status": "panicked", "message": "boom" },
+            "parts": [],
+        }])
+    );
+}
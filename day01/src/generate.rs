@@ -0,0 +1,19 @@
+use crate::Day01;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day01 {
+    /// `size` elves, at least three, each carrying a few snacks.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items = rng.gen_range(1..=6);
+                (0..items)
+                    .map(|_| format!("{}\n", rng.gen_range(1_000..=20_000)))
+                    .collect()
+            })
+            .collect();
+        elves.join("\n")
+    }
+}
@@ -0,0 +1,179 @@
+use crate::Elf;
+use anyhow::bail;
+use common::parse::parse_at;
+use common::ParseError;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// How an inventory of elves' items is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inventory {
+    /// One item per line, with elves separated by blank lines.
+    Text,
+    /// `elf_id,calories` rows, one per item, with each elf's rows together. A first row of
+    /// `elf_id,calories` is a header. Reports name elves by their `elf_id`.
+    Csv,
+    /// An array of elves, each an array of its items.
+    Json,
+}
+
+impl FromStr for Inventory {
+    type Err = anyhow::Error;
+
+    fn from_str(v: &str) -> anyhow::Result<Self> {
+        match v {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            v => bail!("unknown inventory {:?}, expected text, csv or json", v),
+        }
+    }
+}
+
+impl Inventory {
+    /// Guesses the layout from the first line that isn't blank: an opening `[` is JSON, the
+    /// `elf_id,calories` header or a row shaped like one is CSV, and anything else is text.
+    pub fn detect(data: &str) -> Self {
+        let first = data.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+        let fields: Vec<&str> = first.split(',').map(str::trim).collect();
+        if first.trim_start().starts_with('[') {
+            Self::Json
+        } else if fields.len() == 2 && (is_header(&fields) || fields[1].parse::<u64>().is_ok()) {
+            Self::Csv
+        } else {
+            Self::Text
+        }
+    }
+
+    pub fn parse(self, data: &str) -> Result<Vec<Elf>, ParseError> {
+        match self {
+            Self::Text => crate::elf_calories(data),
+            Self::Csv => csv(data),
+            Self::Json => json(data),
+        }
+    }
+}
+
+/// Whether CSV `fields` are the `elf_id,calories` header.
+fn is_header(fields: &[&str]) -> bool {
+    matches!(fields, [id, calories] if id.eq_ignore_ascii_case("elf_id") && calories.eq_ignore_ascii_case("calories"))
+}
+
+fn csv(data: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut elves: Vec<Elf> = Vec::new();
+    // The line each elf's rows ended on, once another elf's rows start.
+    let mut ended: HashMap<&str, Option<usize>> = HashMap::new();
+    let mut current = None;
+    let mut last = 0;
+
+    for (i, line) in data.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [id, calories] = fields[..] else {
+            let message = format!(
+                "expected 2 fields, elf_id and calories, found {}",
+                fields.len()
+            );
+            return Err(ParseError::at(line, line, message).on_line(i + 1));
+        };
+        if elves.is_empty() && is_header(&fields) {
+            continue;
+        }
+        if id.is_empty() {
+            return Err(ParseError::at(line, id, "missing elf_id").on_line(i + 1));
+        }
+        let item: u64 = parse_at(line, calories).map_err(|err| err.on_line(i + 1))?;
+
+        if current != Some(id) {
+            if let Some(previous) = current {
+                ended.insert(previous, Some(last));
+            }
+            if let Some(Some(line_ended)) = ended.insert(id, None) {
+                let message = format!("rows for elf {} already ended on line {}", id, line_ended);
+                return Err(ParseError::at(line, id, message).on_line(i + 1));
+            }
+            elves.push(Elf {
+                id: Some(id.to_string()),
+                ..Elf::default()
+            });
+            current = Some(id);
+        }
+        let elf = elves.last_mut().expect("an elf was just started");
+        elf.calories = elf.calories.checked_add(item).ok_or_else(|| {
+            ParseError::at(line, calories, "elf's calorie total overflows").on_line(i + 1)
+        })?;
+        elf.items.push(item);
+        last = i + 1;
+    }
+    Ok(elves)
+}
+
+fn json(data: &str) -> Result<Vec<Elf>, ParseError> {
+    let items: Vec<Vec<u64>> = serde_json::from_str(data).map_err(|err| {
+        let line = err.line().max(1);
+        let column = err.column().max(1);
+        let text = data.lines().nth(line - 1).unwrap_or("");
+        // serde_json puts the location in its message, which ParseError already shows.
+        let message = err.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default();
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.chars().skip(column - 1).take(20).collect(),
+            message: format!("expected an array of arrays of calorie counts, {}", message),
+        }
+    })?;
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, items)| {
+            let mut calories = 0u64;
+            for (j, &item) in items.iter().enumerate() {
+                calories = calories.checked_add(item).ok_or_else(|| {
+                    let message = format!("elf {}'s calorie total overflows", i + 1);
+                    ParseError::at(data, json_item(data, i, j), message)
+                })?;
+            }
+            Ok(Elf {
+                id: None,
+                calories,
+                items,
+            })
+        })
+        .collect()
+}
+
+/// The text of one elf's item, in JSON already known to be an array of arrays of numbers.
+fn json_item(data: &str, elf: usize, item: usize) -> &str {
+    let (mut depth, mut seen) = (0, 0);
+    let (mut start, mut end) = (0, data.len());
+    for (at, c) in data.char_indices() {
+        match c {
+            '[' => {
+                depth += 1;
+                if depth == 2 {
+                    if seen == elf {
+                        start = at + 1;
+                    }
+                    seen += 1;
+                }
+            }
+            ']' => {
+                if depth == 2 && seen == elf + 1 {
+                    end = at;
+                    break;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    data[start..end]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+        .nth(item)
+        .unwrap_or(&data[start..start])
+}
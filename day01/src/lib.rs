@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use common::parse::parse_at;
+use common::{ParseError, Solution, Stream};
+use std::io::BufRead;
+
+mod generate;
+pub mod inventory;
+pub mod rebalance;
+pub mod stats;
+pub mod top;
+
+pub use inventory::Inventory;
+pub use rebalance::Plan;
+pub use stats::Stats;
+pub use top::{top_k, Top};
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day01;
+
+/// What one elf carries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elf {
+    /// The `elf_id` a CSV inventory gave the elf. Other layouts don't name elves.
+    pub id: Option<String>,
+    /// Total of `items`.
+    pub calories: u64,
+    /// Each item's calories, in input order.
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// How reports name the elf at `index`: its `elf_id` if it has one, or else its number from 1.
+    pub fn name(&self, index: usize) -> String {
+        self.id.clone().unwrap_or_else(|| (index + 1).to_string())
+    }
+}
+
+impl Solution for Day01 {
+    type Input = Vec<Elf>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    /// Reads any [`Inventory`] layout, told apart by [`Inventory::detect`].
+    fn parse(data: &str) -> Result<Vec<Elf>, ParseError> {
+        Inventory::detect(data).parse(data)
+    }
+
+    fn part1(elves: &Vec<Elf>) -> Result<u64> {
+        elves.iter().map(|e| e.calories).max().context("no maximum")
+    }
+
+    fn part2(elves: &Vec<Elf>) -> Result<u64> {
+        let top = top_k(elves.iter().map(|e| e.calories), 3);
+        anyhow::ensure!(!top.is_empty(), "no elves");
+        total(top.iter().map(|&(_, calories)| calories))
+    }
+}
+
+impl Stream for Day01 {
+    /// Reads the text layout only, keeping the current elf's total and the three largest totals
+    /// seen so far.
+    fn stream(reader: impl BufRead) -> Result<(u64, u64)> {
+        let mut top = Top::new(3);
+        for (index, calories) in totals(reader).enumerate() {
+            top.push(index, calories?);
+        }
+        let top = top.into_sorted();
+        let &(_, max) = top.first().context("no maximum")?;
+        Ok((max, total(top.iter().map(|&(_, calories)| calories))?))
+    }
+}
+
+/// Sum of several elves' totals, failing rather than wrapping if it doesn't fit.
+pub fn total(elves: impl IntoIterator<Item = u64>) -> Result<u64> {
+    elves
+        .into_iter()
+        .try_fold(0u64, |sum, v| sum.checked_add(v))
+        .context("total calories overflow")
+}
+
+pub(crate) fn elf_calories(data: &str) -> Result<Vec<Elf>, ParseError> {
+    elves(data.as_bytes()).collect()
+}
+
+/// Each elf's items, read a line at a time. Elves are separated by blank lines, and any number of
+/// them, or of blank lines at either end, separate the same two elves. Every other line must be a
+/// calorie count.
+pub fn elves(reader: impl BufRead) -> impl Iterator<Item = Result<Elf, ParseError>> {
+    group(reader, |elf: &mut Elf, calories| {
+        elf.calories = elf.calories.checked_add(calories)?;
+        elf.items.push(calories);
+        Some(())
+    })
+}
+
+/// Each elf's total calories, read like [`elves`] but without keeping the items, so memory stays
+/// bounded however much an elf carries.
+pub fn totals(reader: impl BufRead) -> impl Iterator<Item = Result<u64, ParseError>> {
+    group(reader, |sum: &mut u64, calories| {
+        *sum = sum.checked_add(calories)?;
+        Some(())
+    })
+}
+
+/// Groups the lines of `reader` into elves, adding each item to an elf with `add`, which gives
+/// `None` if the elf's total overflows.
+fn group<T: Default>(
+    reader: impl BufRead,
+    add: fn(&mut T, u64) -> Option<()>,
+) -> impl Iterator<Item = Result<T, ParseError>> {
+    let mut lines = common::parse::lines(reader);
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let mut elf: Option<T> = None;
+        loop {
+            match lines.next() {
+                Some(Ok((n, line))) => {
+                    if line.trim().is_empty() {
+                        match elf {
+                            Some(elf) => return Some(Ok(elf)),
+                            None => continue,
+                        }
+                    }
+                    if let Err(err) = add_item(elf.get_or_insert_with(T::default), &line, add) {
+                        done = true;
+                        return Some(Err(err.on_line(n)));
+                    }
+                }
+                Some(Err(err)) => {
+                    done = true;
+                    return Some(Err(err));
+                }
+                None => {
+                    done = true;
+                    return elf.map(Ok);
+                }
+            }
+        }
+    })
+}
+
+/// Adds the item on `line` to what an elf carries.
+fn add_item<T>(
+    elf: &mut T,
+    line: &str,
+    add: fn(&mut T, u64) -> Option<()>,
+) -> Result<(), ParseError> {
+    let item = line.trim();
+    let calories: u64 = parse_at(line, item)?;
+    add(elf, calories).ok_or_else(|| ParseError::at(line, item, "elf's calorie total overflows"))
+}
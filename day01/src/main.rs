@@ -1,44 +1,97 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Result};
+use clap::{Parser, Subcommand};
+use common::report::{self, Format};
+use common::{Report, Solution, Source};
+use day01::{top_k, Data, Day01, Elf, Inventory, Plan, Stats};
+use std::time::Instant;
 
-#[derive(rust_embed::RustEmbed)]
-#[folder = "data/"]
-struct Data;
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022, day 1: Calorie Counting")]
+struct Cli {
+    /// Read input from this file, or `-` for stdin, instead of the embedded `input.txt`.
+    #[arg(long, global = true)]
+    input: Option<Source>,
 
-fn main() -> Result<()> {
-    let file = Data::get("example.txt").context("file not found")?;
-    let data = std::str::from_utf8(file.data.as_ref())?;
-    assert_eq!(phase1(&data)?, 24000);
-    assert_eq!(phase2(&data)?, 45000);
+    /// Layout of the input: text, csv or json. Guessed from its first line if not given.
+    #[arg(long, global = true)]
+    inventory: Option<Inventory>,
 
-    let file = Data::get("calories.txt").context("file not found")?;
-    let data = std::str::from_utf8(file.data.as_ref())?;
-    println!("Phase 1: {}", phase1(&data)?);
-    println!("Phase 2: {}", phase2(&data)?);
+    /// How to print the answers, or what a subcommand reports: text, json or tsv.
+    #[arg(long, global = true, default_value = "text")]
+    format: Format,
 
-    Ok(())
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-fn phase1(data: &str) -> Result<i32> {
-    let elves = elf_calories(&data)?;
-    elves.iter().max().context("no maximum").map(|v| *v)
+#[derive(Subcommand)]
+enum Command {
+    /// List the elves carrying the most calories, by CSV `elf_id` or numbered from 1 in input
+    /// order. Elves with the same total are listed in input order.
+    Top {
+        /// How many elves to list. All of them are listed if there are fewer.
+        #[arg(short, default_value_t = 3)]
+        k: usize,
+    },
+    /// Summarize the elves' totals and item counts.
+    Stats {
+        /// Number of equal-width ranges of totals in the histogram.
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+    },
+    /// Plan which items to move between elves so the largest load any elf carries is as small as
+    /// possible. Small inputs are solved exactly and large ones greedily.
+    Rebalance,
 }
 
-fn phase2(data: &str) -> Result<i32> {
-    let mut elves = elf_calories(&data)?;
-    elves.sort();
-    Ok(elves[elves.len() - 3..].iter().sum::<i32>())
-}
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let source = cli.input.unwrap_or_default();
+    let load = || -> Result<Vec<Elf>> {
+        let data = source.read::<Data>()?;
+        parse(&data, &source, cli.inventory)
+    };
 
-fn elf_calories(data: &str) -> Result<Vec<i32>> {
-    let mut sums = vec![0];
-    for line in data.lines() {
-        let idx = sums.len() - 1;
-        match line.parse::<i32>() {
-            Ok(v) => {
-                sums[idx] += v;
+    match cli.command {
+        None => {
+            let data = source.read::<Data>()?;
+            let start = Instant::now();
+            let elves = parse(&data, &source, cli.inventory)?;
+            let parse_ns = start.elapsed().as_nanos() as u64;
+            let solver = common::solver::<Day01>();
+            let report = Report::solve_parsed(1, parse_ns, solver.as_ref(), &elves, &[1, 2]);
+            print!(
+                "{}",
+                report::render(std::slice::from_ref(&report), cli.format)
+            );
+            if report.failed() {
+                bail!("day 1 failed");
             }
-            Err(_) => sums.push(0),
+            Ok(())
+        }
+        Some(Command::Top { k }) => {
+            let elves = load()?;
+            let top = top_k(elves.iter().map(|e| e.calories), k);
+            print!("{}", day01::top::render(&top, &elves, cli.format)?);
+            Ok(())
+        }
+        Some(Command::Stats { buckets }) => {
+            let stats = Stats::new(&load()?, buckets)?;
+            print!("{}", stats.render(cli.format)?);
+            Ok(())
+        }
+        Some(Command::Rebalance) => {
+            print!("{}", Plan::new(&load()?)?);
+            Ok(())
         }
     }
-    Ok(sums)
+}
+
+/// Parses `data` read from `source` in the layout given, or else the one it looks like.
+fn parse(data: &str, source: &Source, inventory: Option<Inventory>) -> Result<Vec<Elf>> {
+    let elves = match inventory {
+        Some(inventory) => inventory.parse(data),
+        None => Day01::parse(data),
+    };
+    Ok(elves.map_err(|err| err.in_file(source.name()))?)
 }
@@ -0,0 +1,326 @@
+use crate::Elf;
+use anyhow::{ensure, Result};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{self, Display};
+
+/// Inputs with at most this many items are partitioned exactly, larger ones greedily.
+pub const EXACT_ITEMS: usize = 16;
+
+/// Placements the exact search may try before settling for the best it has found, which keeps it
+/// well under a second.
+pub const EXACT_BUDGET: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Branch and bound over every partition, so the largest load is as small as it can be.
+    Exact,
+    /// Branch and bound that ran out of budget, so the largest load is the smallest it found. That
+    /// is never more than placing the largest items first would give.
+    BestFound,
+    /// Items moved one at a time from the most loaded elf to the least, so few of them move.
+    Greedy,
+}
+
+/// One item handed from one elf to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub from: usize,
+    /// Index of the item in what `from` carries.
+    pub item: usize,
+    pub calories: u64,
+    pub to: usize,
+}
+
+/// Items to move between elves so the largest calorie load any of them carries is as small as
+/// the method can make it.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub method: Method,
+    /// Largest load before and after the moves.
+    pub before: u64,
+    pub after: u64,
+    /// Each elf's load after the moves.
+    pub loads: Vec<u64>,
+    /// Each elf's [`Elf::name`], for reporting moves.
+    pub names: Vec<String>,
+    /// The moves, by the elf giving the item up and then the item.
+    pub moves: Vec<Move>,
+}
+
+impl Plan {
+    /// Plans with the exact method if there are at most [`EXACT_ITEMS`] items, or else greedily.
+    pub fn new(elves: &[Elf]) -> Result<Self> {
+        let items: usize = elves.iter().map(|e| e.items.len()).sum();
+        match items <= EXACT_ITEMS {
+            true => Self::with(elves, Method::Exact),
+            false => Self::with(elves, Method::Greedy),
+        }
+    }
+
+    /// Plans with `method`. An exact search that runs out of budget keeps the best partition it
+    /// found, as [`Method::BestFound`]. If the plan can't lower the largest load, nothing moves.
+    pub fn with(elves: &[Elf], method: Method) -> Result<Self> {
+        ensure!(!elves.is_empty(), "no elves");
+        crate::total(elves.iter().map(|e| e.calories))?;
+
+        let items: Vec<(usize, usize, u64)> = elves
+            .iter()
+            .enumerate()
+            .flat_map(|(elf, e)| e.items.iter().enumerate().map(move |(i, &c)| (elf, i, c)))
+            .collect();
+        let calories: Vec<u64> = items.iter().map(|&(_, _, c)| c).collect();
+        let start: Vec<usize> = items.iter().map(|&(elf, _, _)| elf).collect();
+        let before = elves.iter().map(|e| e.calories).max().unwrap_or_default();
+
+        let (method, mut bins) = match method {
+            Method::Exact | Method::BestFound => {
+                let partition = exact(&calories, elves.len())?;
+                match partition.optimal {
+                    true => (Method::Exact, partition.bins),
+                    false => (Method::BestFound, partition.bins),
+                }
+            }
+            Method::Greedy => (Method::Greedy, greedy(&calories, &start, elves.len())),
+        };
+        if largest_load(&calories, &bins, elves.len()) >= before {
+            bins = start;
+        }
+
+        let owners = owners(&items, &bins, elves.len());
+        let mut loads = vec![0; elves.len()];
+        let mut moves = Vec::new();
+        for (&(from, item, calories), &bin) in items.iter().zip(&bins) {
+            let to = owners[bin];
+            loads[to] += calories;
+            if to != from {
+                moves.push(Move {
+                    from,
+                    item,
+                    calories,
+                    to,
+                });
+            }
+        }
+        Ok(Self {
+            method,
+            before,
+            after: loads.iter().copied().max().unwrap_or_default(),
+            loads,
+            names: elves.iter().enumerate().map(|(i, e)| e.name(i)).collect(),
+            moves,
+        })
+    }
+}
+
+impl Display for Plan {
+    /// One line per move, elves by name and items numbered from 1, then the change in the largest
+    /// load.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for m in &self.moves {
+            writeln!(
+                f,
+                "Move {} (elf {}, item {}) to elf {}",
+                m.calories,
+                self.names[m.from],
+                m.item + 1,
+                self.names[m.to]
+            )?;
+        }
+        writeln!(
+            f,
+            "{} moves, largest load {} -> {} ({:?})",
+            self.moves.len(),
+            self.before,
+            self.after,
+            self.method
+        )
+    }
+}
+
+/// Each item's bin from [`exact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partition {
+    pub bins: Vec<usize>,
+    /// Whether the search finished, so no partition has a smaller largest load. Otherwise it ran
+    /// out of [`EXACT_BUDGET`] placements and this is the best it found.
+    pub optimal: bool,
+}
+
+/// Assigns `items` to `bins` so the largest bin total is as small as possible, or as small as
+/// [`EXACT_BUDGET`] placements can find. The items' total must fit in a `u64`.
+pub fn exact(items: &[u64], bins: usize) -> Result<Partition> {
+    ensure!(bins > 0, "no bins to assign items to");
+    let best = largest_first(items, bins);
+    let best_max = largest_load(items, &best, bins);
+    let total: u64 = items.iter().sum();
+    let bound = total
+        .div_ceil(bins as u64)
+        .max(items.iter().copied().max().unwrap_or_default());
+    if best_max == bound {
+        return Ok(Partition {
+            bins: best,
+            optimal: true,
+        });
+    }
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&i| Reverse(items[i]));
+    let mut search = Search {
+        items,
+        order,
+        loads: vec![0; bins],
+        bins: vec![0; items.len()],
+        best,
+        best_max,
+        bound,
+        budget: EXACT_BUDGET,
+        exhausted: false,
+    };
+    search.place(0, 0);
+    Ok(Partition {
+        bins: search.best,
+        optimal: !search.exhausted,
+    })
+}
+
+struct Search<'a> {
+    items: &'a [u64],
+    /// Items from largest to smallest, so big ones are placed while there's most room to prune.
+    order: Vec<usize>,
+    loads: Vec<u64>,
+    bins: Vec<usize>,
+    best: Vec<usize>,
+    best_max: u64,
+    /// No partition can have a smaller largest load than this.
+    bound: u64,
+    /// Placements left to try.
+    budget: usize,
+    exhausted: bool,
+}
+
+impl Search<'_> {
+    /// Places the `i`th largest item and those after it, with the loads so far peaking at `max`.
+    /// Returns true once a partition meeting the bound is found or the budget runs out, ending the
+    /// search.
+    fn place(&mut self, i: usize, max: u64) -> bool {
+        if i == self.order.len() {
+            if max < self.best_max {
+                self.best_max = max;
+                self.best = self.bins.clone();
+            }
+            return self.best_max == self.bound;
+        }
+        let item = self.order[i];
+        let calories = self.items[item];
+        // Trying the least loaded bins first finds good partitions early, and bins with the same
+        // load lead to the same partitions, so only the first is tried.
+        let mut bins: Vec<usize> = (0..self.loads.len()).collect();
+        bins.sort_by_key(|&bin| self.loads[bin]);
+        bins.dedup_by_key(|bin| self.loads[*bin]);
+        for bin in bins {
+            let load = self.loads[bin] + calories;
+            if load >= self.best_max {
+                break;
+            }
+            if self.budget == 0 {
+                self.exhausted = true;
+                return true;
+            }
+            self.budget -= 1;
+            self.loads[bin] = load;
+            self.bins[item] = bin;
+            let done = self.place(i + 1, max.max(load));
+            self.loads[bin] -= calories;
+            if done {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Improves `start`, each item's bin, by moving items from the bin with the most to the bin with
+/// the least for as long as that lowers the larger of the two. Each move takes the item that
+/// evens the two out best. The items' total must fit in a `u64`.
+pub fn greedy(items: &[u64], start: &[usize], bins: usize) -> Vec<usize> {
+    let mut assigned = start.to_vec();
+    let mut loads = vec![0; bins];
+    let mut held = vec![Vec::new(); bins];
+    for (i, (&calories, &bin)) in items.iter().zip(start).enumerate() {
+        loads[bin] += calories;
+        held[bin].push(i);
+    }
+    // Every move lowers the sum of the squared loads, so this ends.
+    while let (Some(heavy), Some(light)) = (
+        (0..bins).max_by_key(|&b| (loads[b], Reverse(b))),
+        (0..bins).min_by_key(|&b| (loads[b], b)),
+    ) {
+        let gap = loads[heavy] - loads[light];
+        let Some(pos) = (0..held[heavy].len())
+            .filter(|&p| (1..gap).contains(&items[held[heavy][p]]))
+            .min_by_key(|&p| {
+                let calories = items[held[heavy][p]];
+                (gap - calories).abs_diff(calories)
+            })
+        else {
+            break;
+        };
+        let item = held[heavy].swap_remove(pos);
+        held[light].push(item);
+        loads[heavy] -= items[item];
+        loads[light] += items[item];
+        assigned[item] = light;
+    }
+    assigned
+}
+
+/// Assigns `items` to `bins` largest first, each onto the bin with the least so far, giving each
+/// item's bin. Its largest load is within 4/3 of the best, which bounds the exact search.
+fn largest_first(items: &[u64], bins: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&i| Reverse(items[i]));
+    let mut loads: BinaryHeap<Reverse<(u64, usize)>> =
+        (0..bins).map(|bin| Reverse((0, bin))).collect();
+    let mut assigned = vec![0; items.len()];
+    for i in order {
+        let Some(Reverse((load, bin))) = loads.pop() else {
+            break;
+        };
+        assigned[i] = bin;
+        loads.push(Reverse((load + items[i], bin)));
+    }
+    assigned
+}
+
+fn largest_load(items: &[u64], assigned: &[usize], bins: usize) -> u64 {
+    let mut loads = vec![0; bins];
+    for (&calories, &bin) in items.iter().zip(assigned) {
+        loads[bin] += calories;
+    }
+    loads.into_iter().max().unwrap_or_default()
+}
+
+/// Gives each bin to an elf, greedily pairing those that share the most items so few move.
+fn owners(items: &[(usize, usize, u64)], bins: &[usize], elves: usize) -> Vec<usize> {
+    let mut shared: HashMap<(usize, usize), usize> = HashMap::new();
+    for (&(elf, _, _), &bin) in items.iter().zip(bins) {
+        *shared.entry((bin, elf)).or_default() += 1;
+    }
+    let mut pairs: Vec<_> = shared.into_iter().collect();
+    pairs.sort_by_key(|&((bin, elf), n)| (Reverse(n), bin, elf));
+
+    let mut owners = vec![None; elves];
+    let mut taken = vec![false; elves];
+    for ((bin, elf), _) in pairs {
+        if owners[bin].is_none() && !taken[elf] {
+            owners[bin] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+    let mut free = (0..elves).filter(|&elf| !taken[elf]);
+    owners
+        .into_iter()
+        .map(|owner| owner.or_else(|| free.next()).unwrap_or_default())
+        .collect()
+}
@@ -0,0 +1,215 @@
+use crate::Elf;
+use anyhow::{ensure, Result};
+use common::report::Format;
+use serde::Serialize;
+use std::fmt::{self, Display, Write as _};
+
+/// Percentiles of the totals listed in reports.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Summary of what a group of elves carries.
+#[derive(Debug, Clone, Serialize)]
+pub struct Stats {
+    /// Each elf's name, item count and total, in input order.
+    pub elves: Vec<ElfStats>,
+    pub items: usize,
+    pub calories: u64,
+    /// Mean total per elf.
+    pub mean: f64,
+    /// Middle total, or the mean of the two middle ones for an even number of elves.
+    pub median: f64,
+    /// Nearest-rank percentiles of the totals, for each of [`PERCENTILES`].
+    pub percentiles: Vec<Percentile>,
+    /// Equal-width ranges of totals from the least to the greatest, and how many elves fall in each.
+    pub histogram: Vec<Bucket>,
+    /// The elf carrying the most items, the first in input order on a tie.
+    pub most_items: ElfStats,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElfStats {
+    /// The elf's [`Elf::name`].
+    pub elf: String,
+    pub items: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Percentile {
+    pub percentile: u8,
+    pub calories: u64,
+}
+
+/// Totals from `from` to `to`, inclusive.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+impl Stats {
+    /// Summarizes `elves`, with their totals split into at most `buckets` ranges, and no more
+    /// ranges than elves.
+    pub fn new(elves: &[Elf], buckets: usize) -> Result<Self> {
+        ensure!(!elves.is_empty(), "no elves");
+        ensure!(buckets > 0, "at least one histogram bucket is needed");
+
+        let elves: Vec<ElfStats> = elves
+            .iter()
+            .enumerate()
+            .map(|(index, e)| ElfStats {
+                elf: e.name(index),
+                items: e.items.len(),
+                calories: e.calories,
+            })
+            .collect();
+        let mut totals: Vec<u64> = elves.iter().map(|e| e.calories).collect();
+        totals.sort_unstable();
+
+        let calories = crate::total(totals.iter().copied())?;
+        let n = totals.len();
+        let median = match n % 2 {
+            1 => totals[n / 2] as f64,
+            _ => (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0,
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let rank = (percentile as usize * n).div_ceil(100).max(1);
+                Percentile {
+                    percentile,
+                    calories: totals[rank - 1],
+                }
+            })
+            .collect();
+        let most_items = elves
+            .iter()
+            .rev()
+            .max_by_key(|e| e.items)
+            .expect("elves isn't empty")
+            .clone();
+
+        Ok(Self {
+            items: elves.iter().map(|e| e.items).sum(),
+            calories,
+            mean: calories as f64 / n as f64,
+            median,
+            percentiles,
+            histogram: histogram(&totals, buckets),
+            most_items,
+            elves,
+        })
+    }
+
+    /// Renders the summary in `format`: the table for text, everything including each elf's item
+    /// count and total for JSON, and `section`, `key` and `value` rows for TSV.
+    pub fn render(&self, format: Format) -> Result<String> {
+        Ok(match format {
+            Format::Text => self.to_string(),
+            Format::Json => serde_json::to_string_pretty(self)? + "\n",
+            Format::Tsv => self.tsv()?,
+        })
+    }
+
+    fn tsv(&self) -> Result<String> {
+        let mut out = String::from("section\tkey\tvalue\n");
+        let mut row = |section: &str, key: &dyn Display, value: &dyn Display| {
+            writeln!(out, "{}\t{}\t{}", section, key, value)
+        };
+        row("summary", &"elves", &self.elves.len())?;
+        row("summary", &"items", &self.items)?;
+        row("summary", &"calories", &self.calories)?;
+        row("summary", &"mean", &self.mean)?;
+        row("summary", &"median", &self.median)?;
+        row("summary", &"most_items", &self.most_items.elf)?;
+        for p in &self.percentiles {
+            row("percentile", &p.percentile, &p.calories)?;
+        }
+        for (items, elves) in self.item_counts() {
+            row("items", &items, &elves)?;
+        }
+        for b in &self.histogram {
+            row("histogram", &format_args!("{}-{}", b.from, b.to), &b.elves)?;
+        }
+        Ok(out)
+    }
+
+    /// How many elves carry each number of items, fewest items first.
+    pub fn item_counts(&self) -> Vec<(usize, usize)> {
+        let max = self.most_items.items;
+        let mut counts = vec![0; max + 1];
+        for e in &self.elves {
+            counts[e.items] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, elves)| elves > 0)
+            .collect()
+    }
+}
+
+/// Splits sorted `totals` into `buckets` ranges of equal width, or fewer if there are fewer
+/// elves than that or the totals span fewer values. Widths are worked out in `u128`, as totals
+/// from 0 to `u64::MAX` span one more value than a `u64` holds.
+fn histogram(totals: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (totals[0] as u128, totals[totals.len() - 1] as u128);
+    let span = max - min + 1;
+    let width = span.div_ceil(buckets.min(totals.len()) as u128);
+    let mut histogram: Vec<Bucket> = (0..span.div_ceil(width))
+        .map(|i| Bucket {
+            from: (min + i * width) as u64,
+            to: (min + (i + 1) * width - 1).min(max) as u64,
+            elves: 0,
+        })
+        .collect();
+    for &total in totals {
+        histogram[((total as u128 - min) / width) as usize].elves += 1;
+    }
+    histogram
+}
+
+impl Display for Stats {
+    /// A table of the summary, then the item counts and the histogram with bars.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<12} {:>12}", "Elves", self.elves.len())?;
+        writeln!(f, "{:<12} {:>12}", "Items", self.items)?;
+        writeln!(f, "{:<12} {:>12}", "Calories", self.calories)?;
+        writeln!(f, "{:<12} {:>12.1}", "Mean", self.mean)?;
+        writeln!(f, "{:<12} {:>12.1}", "Median", self.median)?;
+        for p in &self.percentiles {
+            writeln!(f, "{:<12} {:>12}", format!("p{}", p.percentile), p.calories)?;
+        }
+        writeln!(
+            f,
+            "{:<12} {:>12}",
+            "Most items",
+            format!("elf {} ({})", self.most_items.elf, self.most_items.items)
+        )?;
+
+        writeln!(f)?;
+        writeln!(f, "{:>5}  {:>5}", "Items", "Elves")?;
+        for (items, elves) in self.item_counts() {
+            writeln!(f, "{:>5}  {:>5}", items, elves)?;
+        }
+
+        writeln!(f)?;
+        let widest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(1);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        writeln!(f, "{:>w$}  {:>5}", "Calories", "Elves", w = digits * 2 + 1)?;
+        for b in &self.histogram {
+            let bar = "#".repeat((b.elves * 40).div_ceil(widest));
+            writeln!(
+                f,
+                "{:>w$}-{:>w$}  {:>5}  {}",
+                b.from,
+                b.to,
+                b.elves,
+                bar,
+                w = digits
+            )?;
+        }
+        Ok(())
+    }
+}
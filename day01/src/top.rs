@@ -0,0 +1,90 @@
+use crate::Elf;
+use anyhow::Result;
+use common::report::Format;
+use serde_json::json;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Write as _;
+
+/// The `k` elves carrying the most calories, kept in a heap of at most `k` entries so elves can
+/// be offered one at a time. Among elves with the same total, the one offered first ranks higher.
+#[derive(Debug, Clone)]
+pub struct Top {
+    k: usize,
+    /// The worst kept elf on top: least total, then latest offered.
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl Top {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            // `k` may be far more than the elves there are, so the heap grows as they're offered.
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Offers the elf at `index`, keeping it if it beats the worst kept elf.
+    pub fn push(&mut self, index: usize, total: u64) {
+        let entry = Reverse((total, Reverse(index)));
+        if self.heap.len() < self.k {
+            self.heap.push(entry);
+        } else if self.heap.peek().is_some_and(|worst| entry < *worst) {
+            self.heap.pop();
+            self.heap.push(entry);
+        }
+    }
+
+    /// The kept elves as `(index, total)`, best first.
+    pub fn into_sorted(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| (index, total))
+            .collect()
+    }
+}
+
+/// The `k` elves carrying the most calories, given each elf's total, as `(index, total)`, best
+/// first, with ties going to the lower index. Fewer than `k` elves are all returned.
+pub fn top_k(totals: impl IntoIterator<Item = u64>, k: usize) -> Vec<(usize, u64)> {
+    let mut top = Top::new(k);
+    for (index, total) in totals.into_iter().enumerate() {
+        top.push(index, total);
+    }
+    top.into_sorted()
+}
+
+/// Renders `top`, as [`top_k`] gives it for `elves`, in `format`. Every format names elves the
+/// same way, by [`Elf::name`]. Text ends with their total.
+pub fn render(top: &[(usize, u64)], elves: &[Elf], format: Format) -> Result<String> {
+    let total = crate::total(top.iter().map(|&(_, calories)| calories))?;
+    let top: Vec<(String, u64)> = top
+        .iter()
+        .map(|&(index, calories)| (elves[index].name(index), calories))
+        .collect();
+    let mut out = String::new();
+    match format {
+        Format::Text => {
+            for (elf, calories) in &top {
+                writeln!(out, "Elf {}: {}", elf, calories)?;
+            }
+            writeln!(out, "Total: {}", total)?;
+        }
+        Format::Json => {
+            let elves: Vec<_> = top
+                .iter()
+                .map(|(elf, calories)| json!({ "elf": elf, "calories": calories }))
+                .collect();
+            let top = json!({ "elves": elves, "total": total });
+            writeln!(out, "{}", serde_json::to_string_pretty(&top)?)?;
+        }
+        Format::Tsv => {
+            writeln!(out, "elf\tcalories")?;
+            for (elf, calories) in &top {
+                writeln!(out, "{}\t{}", elf, calories)?;
+            }
+        }
+    }
+    Ok(out)
+}
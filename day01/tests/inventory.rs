@@ -0,0 +1,81 @@
+use common::Solution;
+use day01::{Day01, Inventory};
+
+const TEXT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+const CSV: &str = "elf_id,calories\n1,1000\n1,2000\n1,3000\n2,4000\n3,5000\n3,6000\n\
+    4,7000\n4,8000\n4,9000\n5,10000\n";
+const JSON: &str = "[[1000, 2000, 3000], [4000], [5000, 6000], [7000, 8000, 9000], [10000]]\n";
+
+#[test]
+fn every_layout_reads_the_same_elves() {
+    assert_eq!(Inventory::detect(TEXT), Inventory::Text);
+    assert_eq!(Inventory::detect(CSV), Inventory::Csv);
+    assert_eq!(Inventory::detect(JSON), Inventory::Json);
+
+    let items = |data| -> Vec<Vec<u64>> {
+        Day01::parse(data)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.items)
+            .collect()
+    };
+    let elves = items(TEXT);
+    assert_eq!(items(CSV), elves);
+    assert_eq!(items(&CSV[CSV.find('\n').unwrap() + 1..]), elves);
+    assert_eq!(items(JSON), elves);
+    assert_eq!(Day01::part2(&Day01::parse(CSV).unwrap()).unwrap(), 45000);
+}
+
+#[test]
+fn csv_needs_its_header_or_rows_shaped_like_it() {
+    assert_eq!(Inventory::detect("\nELF_ID, Calories\n"), Inventory::Csv);
+    assert_eq!(Inventory::detect("a, 12\n"), Inventory::Csv);
+    assert_eq!(Inventory::detect("1000,\n2000\n"), Inventory::Text);
+    assert_eq!(Inventory::detect("a,b\n"), Inventory::Text);
+    assert_eq!(Inventory::detect("a,1,2\n"), Inventory::Text);
+    let err = Day01::parse("1000,\n").unwrap_err();
+    assert_eq!((err.line, err.text.as_str()), (1, "1000,"));
+}
+
+#[test]
+fn csv_keeps_each_elfs_id() {
+    let elves = Day01::parse("elf_id,calories\nx7,3\nx7,4\nq,1\n").unwrap();
+    let ids: Vec<_> = elves.iter().map(|e| e.id.as_deref()).collect();
+    assert_eq!(ids, [Some("x7"), Some("q")]);
+    assert_eq!(
+        (elves[0].name(0), elves[1].name(1)),
+        ("x7".into(), "q".into())
+    );
+    assert_eq!(Day01::parse(TEXT).unwrap()[2].name(2), "3");
+}
+
+#[test]
+fn inconsistent_csv_rows_are_errors() {
+    let err = Day01::parse("a,1\nb,2\n\na,3\n").unwrap_err();
+    assert_eq!((err.line, err.text.as_str()), (4, "a"));
+    assert!(err.message.contains("ended on line 1"), "{}", err);
+
+    let err = Day01::parse("a,1\nb,2,3\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+    let err = Day01::parse("a,1\n,2\n").unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (2, "missing elf_id"));
+    let err = Day01::parse("a,1\na, 2x\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "2x"));
+}
+
+#[test]
+fn json_errors_point_into_the_input() {
+    let err = Day01::parse("[[1, 2],\n [3, \"4\"]]").unwrap_err();
+    assert_eq!(err.line, 2);
+    let err = Inventory::Json.parse("1\n2\n").unwrap_err();
+    assert_eq!(err.line, 1);
+}
+
+#[test]
+fn json_overflow_points_at_the_item() {
+    let err = Inventory::Json
+        .parse("[[1],\n [18446744073709551615, 1], [2]]")
+        .unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 25, "1"));
+    assert_eq!(err.message, "elf 2's calorie total overflows");
+}
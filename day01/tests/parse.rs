@@ -0,0 +1,42 @@
+use common::Solution;
+use day01::{Day01, Elf};
+
+fn totals(data: &str) -> Vec<u64> {
+    Day01::parse(data)
+        .unwrap()
+        .iter()
+        .map(|e| e.calories)
+        .collect()
+}
+
+#[test]
+fn blank_lines_separate_elves() {
+    let elves = Day01::parse("\n\n1000\n2000\n\n\n\n3000\n\n4000\n5000\n\n\n").unwrap();
+    let elf = |items: &[u64]| Elf {
+        id: None,
+        calories: items.iter().sum(),
+        items: items.to_vec(),
+    };
+    assert_eq!(
+        elves,
+        [elf(&[1000, 2000]), elf(&[3000]), elf(&[4000, 5000])]
+    );
+    assert_eq!(totals("1000\r\n\r\n2000\r\n"), [1000, 2000]);
+    assert!(Day01::parse("\n\n").unwrap().is_empty());
+}
+
+#[test]
+fn malformed_lines_are_reported_where_they_are() {
+    let err = Day01::parse("1000\n\n12a4\n2000\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "12a4"));
+    let err = Day01::parse("1000\n  -5\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "-5"));
+}
+
+#[test]
+fn totals_past_u32_are_kept_and_overflow_is_an_error() {
+    assert_eq!(totals("4000000000\n4000000000\n"), [8_000_000_000]);
+    let err = Day01::parse("1\n\n18446744073709551615\n1\n").unwrap_err();
+    assert_eq!((err.line, err.text.as_str()), (4, "1"));
+    assert!(err.message.contains("overflows"), "{}", err);
+}
@@ -0,0 +1,147 @@
+use common::Solution;
+use day01::rebalance::{self, Method};
+use day01::{Day01, Elf, Plan};
+use proptest::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn elves(items: &[Vec<u64>]) -> Vec<Elf> {
+    items
+        .iter()
+        .map(|items| Elf {
+            id: None,
+            calories: items.iter().sum(),
+            items: items.clone(),
+        })
+        .collect()
+}
+
+/// The smallest largest load over every assignment of `items` to `bins`.
+fn brute_force(items: &[u64], bins: usize) -> u64 {
+    let mut best = u64::MAX;
+    for mut code in 0..bins.pow(items.len() as u32) {
+        let mut loads = vec![0; bins];
+        for &item in items {
+            loads[code % bins] += item;
+            code /= bins;
+        }
+        best = best.min(loads.into_iter().max().unwrap_or_default());
+    }
+    best
+}
+
+/// Carries out `plan`'s moves, checking each one names the item it moves.
+fn apply(elves: &[Elf], plan: &Plan) -> Vec<u64> {
+    let mut loads: Vec<u64> = elves.iter().map(|e| e.calories).collect();
+    for m in &plan.moves {
+        assert_eq!(elves[m.from].items[m.item], m.calories);
+        loads[m.from] -= m.calories;
+        loads[m.to] += m.calories;
+    }
+    loads
+}
+
+#[test]
+fn evens_out_the_example() {
+    let data = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    let elves = Day01::parse(data).unwrap();
+    let plan = Plan::new(&elves).unwrap();
+    assert_eq!(plan.method, Method::Exact);
+    assert_eq!((plan.before, plan.after), (24000, 11000));
+    assert_eq!(plan.loads, [11000; 5]);
+    assert_eq!(apply(&elves, &plan), plan.loads);
+}
+
+#[test]
+fn nothing_moves_when_it_cant_help() {
+    let elves = elves(&[vec![9], vec![1, 1], vec![2]]);
+    for method in [Method::Exact, Method::Greedy] {
+        let plan = Plan::with(&elves, method).unwrap();
+        assert!(plan.moves.is_empty());
+        assert_eq!(plan.after, 9);
+    }
+}
+
+#[test]
+fn exact_plans_finish_within_budget_at_the_largest_size() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for elves in [2, 3, 4, 6, 8] {
+        let items: Vec<Vec<u64>> = (0..elves)
+            .map(|_| {
+                (0..rebalance::EXACT_ITEMS / elves)
+                    .map(|_| rng.gen_range(100_000_000..10_000_000_000))
+                    .collect()
+            })
+            .collect();
+        let elves = self::elves(&items);
+        let plan = Plan::new(&elves).unwrap();
+        assert_eq!(plan.method, Method::Exact, "{} elves", elves.len());
+        assert_eq!(apply(&elves, &plan), plan.loads);
+    }
+}
+
+proptest! {
+    #[test]
+    fn exact_matches_brute_force(items in prop::collection::vec(1u64..20, 0..7), bins in 1usize..4) {
+        let partition = rebalance::exact(&items, bins).unwrap();
+        prop_assert!(partition.optimal);
+        let assigned = partition.bins;
+        let mut loads = vec![0; bins];
+        for (&item, &bin) in items.iter().zip(&assigned) {
+            loads[bin] += item;
+        }
+        prop_assert_eq!(loads.into_iter().max().unwrap_or_default(), brute_force(&items, bins));
+    }
+
+    #[test]
+    fn plans_carry_out_to_their_loads(
+        items in prop::collection::vec(prop::collection::vec(0u64..50, 0..4), 1..4),
+        greedy in any::<bool>(),
+    ) {
+        let elves = elves(&items);
+        let method = if greedy { Method::Greedy } else { Method::Exact };
+        let plan = Plan::with(&elves, method).unwrap();
+        prop_assert!(plan.after <= plan.before);
+        prop_assert_eq!(apply(&elves, &plan), plan.loads.clone());
+        prop_assert_eq!(plan.loads.iter().max().copied().unwrap_or_default(), plan.after);
+        if !greedy {
+            let all: Vec<u64> = items.concat();
+            prop_assert_eq!(plan.after, brute_force(&all, elves.len()).min(plan.before));
+        }
+    }
+}
+
+#[test]
+fn searches_out_of_budget_keep_the_best_partition_found() {
+    // An odd number of items that are each 2 more than a multiple of 4 can't split evenly in two,
+    // so the search can't stop at the lower bound and runs out of budget ruling partitions out.
+    let mut rng = StdRng::seed_from_u64(0);
+    let items: Vec<Vec<u64>> = (0..2)
+        .map(|elf| {
+            (0..21 - elf)
+                .map(|_| rng.gen_range(1_000_000..100_000_000) * 4 + 2)
+                .collect()
+        })
+        .collect();
+    let all = items.concat();
+    let partition = rebalance::exact(&all, 2).unwrap();
+    assert!(!partition.optimal);
+
+    let elves = elves(&items);
+    let plan = Plan::with(&elves, Method::Exact).unwrap();
+    assert_eq!(plan.method, Method::BestFound);
+    assert_eq!(apply(&elves, &plan), plan.loads);
+    let mut sorted = all.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let mut largest_first = [0, 0];
+    for item in sorted {
+        *largest_first.iter_mut().min().unwrap() += item;
+    }
+    assert!(plan.after <= largest_first.into_iter().max().unwrap());
+    assert!(plan.after < plan.before);
+}
+
+#[test]
+fn exact_needs_a_bin() {
+    assert!(rebalance::exact(&[1, 2], 0).is_err());
+}
@@ -0,0 +1,62 @@
+use common::Solution;
+use day01::{Day01, Stats};
+
+const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn summarizes_the_example() {
+    let stats = Stats::new(&Day01::parse(EXAMPLE).unwrap(), 3).unwrap();
+    assert_eq!((stats.items, stats.calories), (10, 55000));
+    assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+    let percentiles: Vec<_> = stats.percentiles.iter().map(|p| p.calories).collect();
+    assert_eq!(percentiles, [4000, 6000, 11000, 24000, 24000]);
+    // The first and fourth elves both carry three items.
+    assert_eq!(stats.most_items.elf, "1");
+    assert_eq!(stats.item_counts(), [(1, 2), (2, 1), (3, 2)]);
+
+    let buckets: Vec<_> = stats
+        .histogram
+        .iter()
+        .map(|b| (b.from, b.to, b.elves))
+        .collect();
+    assert_eq!(
+        buckets,
+        [(4000, 10666, 3), (10667, 17333, 1), (17334, 24000, 1)]
+    );
+}
+
+#[test]
+fn median_of_an_even_count_is_the_mean_of_the_middle_two() {
+    let stats = Stats::new(&Day01::parse("1\n\n2\n\n4\n\n8\n").unwrap(), 10).unwrap();
+    assert_eq!(stats.median, 3.0);
+    // There are never more buckets than elves.
+    let buckets: Vec<_> = stats
+        .histogram
+        .iter()
+        .map(|b| (b.from, b.to, b.elves))
+        .collect();
+    assert_eq!(buckets, [(1, 2, 2), (3, 4, 1), (5, 6, 0), (7, 8, 1)]);
+    assert!(Stats::new(&[], 10).is_err());
+}
+
+#[test]
+fn histograms_cover_the_whole_range_of_u64() {
+    let elves = Day01::parse("0\n\n18446744073709551615\n").unwrap();
+    let stats = Stats::new(&elves, 1).unwrap();
+    let buckets: Vec<_> = stats
+        .histogram
+        .iter()
+        .map(|b| (b.from, b.to, b.elves))
+        .collect();
+    assert_eq!(buckets, [(0, u64::MAX, 2)]);
+    let stats = Stats::new(&elves, 2).unwrap();
+    assert_eq!(stats.histogram[1].to, u64::MAX);
+    assert_eq!(stats.histogram.iter().map(|b| b.elves).sum::<usize>(), 2);
+}
+
+#[test]
+fn huge_bucket_counts_are_capped_at_the_elves() {
+    let elves = Day01::parse("1\n\n1000000000000\n").unwrap();
+    let stats = Stats::new(&elves, usize::MAX).unwrap();
+    assert_eq!(stats.histogram.len(), 2);
+}
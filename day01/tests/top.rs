@@ -0,0 +1,62 @@
+use common::report::Format;
+use common::Solution;
+use day01::{top, top_k, Day01};
+use proptest::prelude::*;
+
+#[test]
+fn ties_go_to_the_earlier_elf() {
+    assert_eq!(top_k([5, 9, 7, 9, 5], 3), [(1, 9), (3, 9), (2, 7)]);
+    assert_eq!(top_k([5, 9, 7, 9, 5], 4), [(1, 9), (3, 9), (2, 7), (0, 5)]);
+}
+
+#[test]
+fn k_past_the_number_of_elves_returns_them_all() {
+    assert_eq!(top_k([1, 3, 2], 10), [(1, 3), (2, 2), (0, 1)]);
+    assert!(top_k([1, 3, 2], 0).is_empty());
+    assert!(top_k(Vec::new(), 3).is_empty());
+    assert_eq!(top_k([1, 3, 2], usize::MAX), [(1, 3), (2, 2), (0, 1)]);
+}
+
+proptest! {
+    /// Few distinct totals keep ties common.
+    #[test]
+    fn top_k_matches_sorting(elves in prop::collection::vec(0u64..5, 0..30), k in 0usize..35) {
+        let mut sorted: Vec<(usize, u64)> = elves.iter().copied().enumerate().collect();
+        sorted.sort_by_key(|&(index, total)| (std::cmp::Reverse(total), index));
+        sorted.truncate(k);
+        prop_assert_eq!(top_k(elves, k), sorted);
+    }
+}
+
+#[test]
+fn renders_in_every_format() {
+    let elves = Day01::parse("5\n\n9\n\n7\n").unwrap();
+    let top = top_k(elves.iter().map(|e| e.calories), 2);
+    assert_eq!(
+        top::render(&top, &elves, Format::Text).unwrap(),
+        "Elf 2: 9\nElf 3: 7\nTotal: 16\n"
+    );
+    assert_eq!(
+        top::render(&top, &elves, Format::Tsv).unwrap(),
+        "elf\tcalories\n2\t9\n3\t7\n"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&top::render(&top, &elves, Format::Json).unwrap()).unwrap();
+    assert_eq!(json["total"], 16);
+    assert_eq!(json["elves"][0]["elf"], "2");
+    assert_eq!(json["elves"][1]["elf"], "3");
+}
+
+#[test]
+fn csv_elves_are_reported_by_their_ids() {
+    let elves = Day01::parse("elf_id,calories\nalice,5\nbob,9\nbob,1\ncarol,7\n").unwrap();
+    let top = top_k(elves.iter().map(|e| e.calories), 2);
+    assert_eq!(
+        top::render(&top, &elves, Format::Text).unwrap(),
+        "Elf bob: 10\nElf carol: 7\nTotal: 17\n"
+    );
+    assert_eq!(
+        top::render(&top, &elves, Format::Tsv).unwrap(),
+        "elf\tcalories\nbob\t10\ncarol\t7\n"
+    );
+}
@@ -0,0 +1,17 @@
+use crate::Day02;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+impl Generate for Day02 {
+    /// `size` rounds.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let a = ["A", "B", "C"].choose(rng).unwrap();
+                let b = ["X", "Y", "Z"].choose(rng).unwrap();
+                format!("{} {}\n", a, b)
+            })
+            .collect()
+    }
+}
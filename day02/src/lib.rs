@@ -0,0 +1,74 @@
+use anyhow::{Context, Result};
+use common::parse::split_at;
+use common::{ParseError, Solution, Stream};
+use std::io::BufRead;
+
+mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+/// Strategy guide columns, left as text since each part reads the second column differently.
+pub type Guide = Vec<(String, String)>;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Guide;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(data: &str) -> Result<Guide, ParseError> {
+        rounds(data.as_bytes()).collect()
+    }
+
+    fn part1(guide: &Guide) -> Result<u64> {
+        part1::run(guide)
+    }
+
+    fn part2(guide: &Guide) -> Result<u64> {
+        part2::run(guide)
+    }
+}
+
+impl Stream for Day02 {
+    fn stream(reader: impl BufRead) -> Result<(u64, u64)> {
+        let (mut one, mut two) = (0u64, 0u64);
+        for round in rounds(reader) {
+            let (a, b) = round?;
+            one = total([one, part1::score(&a, &b)])?;
+            two = total([two, part2::score(&a, &b)])?;
+        }
+        Ok((one, two))
+    }
+}
+
+/// Sum of round scores, failing rather than wrapping if it doesn't fit.
+pub fn total(scores: impl IntoIterator<Item = u64>) -> Result<u64> {
+    scores
+        .into_iter()
+        .try_fold(0u64, |sum, v| sum.checked_add(v))
+        .context("total score overflows")
+}
+
+/// The guide's rounds, read a line at a time.
+pub fn rounds(reader: impl BufRead) -> impl Iterator<Item = Result<(String, String), ParseError>> {
+    common::parse::lines(reader).map(|line| {
+        let (n, line) = line?;
+        parse_round(&line).map_err(|err| err.on_line(n))
+    })
+}
+
+fn parse_round(line: &str) -> Result<(String, String), ParseError> {
+    let (a, b) = split_at(line, line, " ")?;
+    if !["A", "B", "C"].contains(&a) {
+        return Err(ParseError::at(line, a, "expected A, B or C"));
+    }
+    if !["X", "Y", "Z"].contains(&b) {
+        return Err(ParseError::at(line, b, "expected X, Y or Z"));
+    }
+    Ok((a.to_string(), b.to_string()))
+}
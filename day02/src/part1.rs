@@ -1,18 +1,18 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 
-pub fn run(data: &str) -> Result<i32> {
-    let mut rounds = Vec::new();
-    for line in data.lines() {
-        let round: Round = line.split_once(" ").context("no split")?.into();
-        rounds.push(round);
-    }
-    Ok(rounds.iter().fold(0, |sum, r| sum + r.score()))
+pub fn run(guide: &[(String, String)]) -> Result<u64> {
+    crate::total(guide.iter().map(|(a, b)| score(a, b)))
+}
+
+/// Score of one round of the guide.
+pub fn score(a: &str, b: &str) -> u64 {
+    Round::from((a, b)).score()
 }
 
 struct Round(Play, Play);
 
 impl Round {
-    fn score(&self) -> i32 {
+    fn score(&self) -> u64 {
         self.outcome().score() + self.1.score()
     }
 
@@ -42,7 +42,7 @@ enum Outcome {
 }
 
 impl Outcome {
-    fn score(&self) -> i32 {
+    fn score(&self) -> u64 {
         match self {
             Self::Loss => 0,
             Self::Draw => 3,
@@ -60,7 +60,7 @@ enum Play {
 }
 
 impl Play {
-    fn score(&self) -> i32 {
+    fn score(&self) -> u64 {
         match self {
             Self::Rock => 1,
             Self::Paper => 2,
@@ -0,0 +1,9 @@
+use day02::total;
+
+#[test]
+fn totals_fail_rather_than_wrap() {
+    assert_eq!(total([6, 3, 9]).unwrap(), 18);
+    assert_eq!(total([u32::MAX as u64, 9]).unwrap(), u32::MAX as u64 + 9);
+    let err = total([u64::MAX - 3, 9]).unwrap_err();
+    assert_eq!(err.to_string(), "total score overflows");
+}
@@ -0,0 +1,33 @@
+use crate::Day03;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day03 {
+    /// `size` rucksacks, rounded up to whole groups of three. Each rucksack has exactly one item
+    /// in both compartments and each group exactly one badge.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+            letters.shuffle(rng);
+            let (badge, shared, pools) = (letters[0], &letters[1..4], &letters[4..]);
+
+            for (i, &shared) in shared.iter().enumerate() {
+                let (left_pool, right_pool) =
+                    (&pools[i * 16..i * 16 + 8], &pools[i * 16 + 8..][..8]);
+                let fill = rng.gen_range(1..=12);
+                let mut left = vec![badge, shared];
+                left.extend((0..fill).map(|_| *left_pool.choose(rng).unwrap()));
+                let mut right = vec![shared];
+                right.extend((0..fill + 1).map(|_| *right_pool.choose(rng).unwrap()));
+                left.shuffle(rng);
+                right.shuffle(rng);
+                out.extend(left.into_iter().chain(right));
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
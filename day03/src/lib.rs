@@ -0,0 +1,57 @@
+use anyhow::Result;
+use common::{ParseError, Solution};
+
+mod generate;
+pub mod part1;
+pub mod part2;
+mod priority;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Vec<String>, ParseError> {
+        let mut sacks = Vec::new();
+        for line in data.lines() {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                let bad = &line[i..i + line[i..].chars().next().map_or(1, char::len_utf8)];
+                return Err(ParseError::at(data, bad, "items must be letters"));
+            }
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    "compartments aren't the same size",
+                ));
+            }
+            sacks.push(line.to_string());
+        }
+        if sacks.len() % 3 != 0 {
+            let first = data
+                .lines()
+                .nth(sacks.len() - sacks.len() % 3)
+                .unwrap_or_default();
+            let message = format!(
+                "groups are three elves, but the last has {} rucksacks",
+                sacks.len() % 3
+            );
+            return Err(ParseError::at(data, first, message));
+        }
+        Ok(sacks)
+    }
+
+    fn part1(sacks: &Vec<String>) -> Result<u32> {
+        part1::run(sacks)
+    }
+
+    fn part2(sacks: &Vec<String>) -> Result<u32> {
+        part2::run(sacks)
+    }
+}
@@ -3,18 +3,18 @@ use anyhow::Result;
 use std::collections::HashSet;
 use std::ops::Deref;
 
-pub fn run(data: &str) -> Result<u32> {
+pub fn run(sacks: &[String]) -> Result<u32> {
     let scores = priority::scores();
 
-    let sum = data.lines().fold(0, |accum, line| {
-        let sack: Sack = line.into();
+    let sum = sacks.iter().fold(0, |accum, line| {
+        let sack: Sack = line.as_str().into();
         let score = sack
             // Intersect the two compartments to find common items.
             .intersection()
             // Accumulate the priority sum of the common items.
             .iter()
             .fold(0, |accum, c| {
-                let pos = scores.find(c.clone()).unwrap() as u32;
+                let pos = scores.find(*c).unwrap() as u32;
                 accum + pos + 1
             });
 
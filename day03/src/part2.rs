@@ -4,9 +4,9 @@ use itertools::Itertools;
 use std::collections::HashSet;
 use std::ops::Deref;
 
-pub fn run(data: &str) -> Result<u32> {
+pub fn run(sacks: &[String]) -> Result<u32> {
     let scores = priority::scores();
-    let chunks = data.lines().into_iter().chunks(3);
+    let chunks = sacks.iter().map(String::as_str).chunks(3);
 
     let sum = chunks.into_iter().fold(0, |accum, chunk| {
         // For each line in the chunk, map them into an ItemSet and collect them into a vector.
@@ -22,7 +22,7 @@ pub fn run(data: &str) -> Result<u32> {
             // Accumulate the priority sum of the common items.
             .iter()
             .fold(0, |accum, c| {
-                let pos = scores.find(c.clone()).unwrap() as u32;
+                let pos = scores.find(*c).unwrap() as u32;
                 accum + pos + 1
             });
 
@@ -0,0 +1,12 @@
+use common::testing::parse_error;
+use day03::Day03;
+
+#[test]
+fn reports_incomplete_groups_at_their_first_rucksack() {
+    let data = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFMfFZSrLr\n";
+    assert_eq!(
+        parse_error::<Day03>(data),
+        (1, 1, "vJrwpWtwJgWrhcsFMMfFFhFp".into())
+    );
+    assert_eq!(parse_error::<Day03>("abcd\nab1d\n").0, 2);
+}
@@ -0,0 +1,17 @@
+use crate::Day04;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day04 {
+    /// `size` pairs of section assignments.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            format!("{}-{}", start, rng.gen_range(start..=99))
+        };
+        (0..size)
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
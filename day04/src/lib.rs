@@ -0,0 +1,66 @@
+use anyhow::Result;
+use common::parse::{parse_at, split_at};
+use common::{ParseError, Solution, Stream};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+/// Section assignments for a pair of elves.
+pub type Pair = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Vec<Pair>, ParseError> {
+        pairs(data.as_bytes()).collect()
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> Result<usize> {
+        Ok(part1::run(pairs))
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> Result<usize> {
+        Ok(part2::run(pairs))
+    }
+}
+
+impl Stream for Day04 {
+    fn stream(reader: impl BufRead) -> Result<(usize, usize)> {
+        let (mut one, mut two) = (0, 0);
+        for pair in pairs(reader) {
+            let pair = pair?;
+            one += part1::contains(&pair) as usize;
+            two += part2::overlaps(&pair) as usize;
+        }
+        Ok((one, two))
+    }
+}
+
+/// The pairs' assignments, read a line at a time.
+pub fn pairs(reader: impl BufRead) -> impl Iterator<Item = Result<Pair, ParseError>> {
+    common::parse::lines(reader).map(|line| {
+        let (n, line) = line?;
+        parse_pair(&line).map_err(|err| err.on_line(n))
+    })
+}
+
+fn parse_pair(line: &str) -> Result<Pair, ParseError> {
+    let (one, two) = split_at(line, line, ",")?;
+    Ok((parse_range(line, one)?, parse_range(line, two)?))
+}
+
+fn parse_range(data: &str, v: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let (start, end) = split_at(data, v, "-")?;
+    Ok(parse_at(data, start)?..=parse_at(data, end)?)
+}
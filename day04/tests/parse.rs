@@ -0,0 +1,14 @@
+use common::testing::parse_error;
+use day04::Day04;
+
+#[test]
+fn reports_bad_pairs_where_they_are() {
+    assert_eq!(
+        parse_error::<Day04>("2-4,6-8\n2-x,4-5\n"),
+        (2, 3, "x".into())
+    );
+    assert_eq!(
+        parse_error::<Day04>("2-4,6-8\n2-3;4-5\n"),
+        (2, 1, "2-3;4-5".into())
+    );
+}
@@ -0,0 +1,45 @@
+use crate::Day05;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day05 {
+    /// Nine stacks and `size` moves. No move empties a stack, so every stack has a top crate.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        const STACKS: usize = 9;
+        let heights: Vec<usize> = (0..STACKS).map(|_| rng.gen_range(1..=8)).collect();
+        let tallest = *heights.iter().max().unwrap();
+
+        let mut out = String::new();
+        for level in (0..tallest).rev() {
+            let row: Vec<String> = heights
+                .iter()
+                .map(|&h| match h > level {
+                    true => format!("[{}]", rng.gen_range(b'A'..=b'Z') as char),
+                    false => "   ".into(),
+                })
+                .collect();
+            out += &row.join(" ");
+            out.push('\n');
+        }
+        let labels: Vec<String> = (1..=STACKS).map(|n| format!(" {} ", n)).collect();
+        out += &labels.join(" ");
+        out += "\n\n";
+
+        let mut heights = heights;
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.gen_range(0..STACKS);
+                if heights[from] > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..STACKS)) % STACKS;
+            let count = rng.gen_range(1..heights[from]);
+            heights[from] -= count;
+            heights[to] += count;
+            out += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+        }
+        out
+    }
+}
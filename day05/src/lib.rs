@@ -0,0 +1,122 @@
+use anyhow::{Context, Result};
+use common::parse::parse_at;
+use common::{ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub type Stacks = HashMap<usize, Vec<char>>;
+
+#[derive(Debug)]
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+#[derive(Debug)]
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Procedure, ParseError> {
+        let (stacks, moves): (&str, &str) = data
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(data, "no blank line between stacks and moves"))?;
+
+        Ok(Procedure {
+            stacks: parse_stacks(stacks),
+            moves: parse_moves(data, moves)?,
+        })
+    }
+
+    fn part1(procedure: &Procedure) -> Result<String> {
+        part1::run(procedure)
+    }
+
+    fn part2(procedure: &Procedure) -> Result<String> {
+        part2::run(procedure)
+    }
+}
+
+fn tops(stacks: &Stacks) -> Result<String> {
+    let max_key = stacks.keys().cloned().max().context("no keys")?;
+
+    let mut tops = Vec::<char>::new();
+    for i in 0..=max_key {
+        let ch = stacks
+            .get(&i)
+            .context("no stack at index")?
+            .last()
+            .context("no elements")?;
+        tops.push(*ch);
+    }
+    Ok(tops.iter().collect())
+}
+
+fn parse_stacks(text: &str) -> Stacks {
+    let mut stacks = Stacks::new();
+    for line in text.lines().rev().skip(1) {
+        let chars = line.chars().collect::<Vec<char>>();
+        let moves = chars
+            .chunks(4)
+            .enumerate()
+            .filter(|chunk| !chunk.1[1].is_whitespace())
+            .map(|chunk| (chunk.0, chunk.1[1]));
+
+        for m in moves {
+            match stacks.get_mut(&m.0) {
+                Some(stack) => {
+                    stack.push(m.1);
+                }
+                None => {
+                    stacks.insert(m.0, vec![m.1]);
+                }
+            }
+        }
+    }
+    stacks
+}
+
+lazy_static! {
+    static ref MOVE_PATTERN: Regex =
+        Regex::new(r"^move\s+(\d+)\s+from\s+(\d+)\s+to\s+(\d+)$").unwrap();
+}
+
+fn parse_moves(data: &str, moves: &str) -> Result<Vec<Move>, ParseError> {
+    moves.lines().map(|line| parse_move(data, line)).collect()
+}
+
+fn parse_move(data: &str, v: &str) -> Result<Move, ParseError> {
+    let captures = MOVE_PATTERN
+        .captures(v)
+        .ok_or_else(|| ParseError::at(data, v, "expected \"move N from A to B\""))?;
+    let stack = |i: usize| -> Result<usize, ParseError> {
+        let text = captures.get(i).unwrap().as_str();
+        match parse_at::<usize>(data, text)? {
+            0 => Err(ParseError::at(data, text, "stacks are numbered from 1")),
+            n => Ok(n - 1),
+        }
+    };
+    Ok(Move {
+        count: parse_at(data, captures.get(1).unwrap().as_str())?,
+        from: stack(2)?,
+        to: stack(3)?,
+    })
+}
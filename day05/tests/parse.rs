@@ -0,0 +1,15 @@
+use common::testing::parse_error;
+use day05::Day05;
+
+const STACKS: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+#[test]
+fn reports_bad_moves_where_they_are() {
+    let data = format!("{}move 1 from 2 to 1\nmove x from 1 to 3\n", STACKS);
+    assert_eq!(
+        parse_error::<Day05>(&data),
+        (7, 1, "move x from 1 to 3".into())
+    );
+    let data = format!("{}move 1 form 2 to 1\n", STACKS);
+    assert_eq!(parse_error::<Day05>(&data).0, 6);
+}
@@ -0,0 +1,20 @@
+use crate::Day06;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day06 {
+    /// A signal of `size` characters, at least 14, with a start-of-message marker somewhere in it.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(14);
+        let mut signal: Vec<u8> = (0..size).map(|_| rng.gen_range(b'a'..=b'e')).collect();
+
+        let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+        marker.shuffle(rng);
+        let at = rng.gen_range(0..=size - 14);
+        signal[at..at + 14].copy_from_slice(&marker[..14]);
+
+        String::from_utf8(signal).unwrap() + "\n"
+    }
+}
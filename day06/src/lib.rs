@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution, Stream};
+use std::io::BufRead;
+
+mod generate;
+pub mod reference;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<String, ParseError> {
+        Ok(data.to_string())
+    }
+
+    /// Finds the start-of-packet marker.
+    fn part1(signal: &String) -> Result<usize> {
+        detect(signal, 4)
+    }
+
+    /// Finds the start-of-message marker.
+    fn part2(signal: &String) -> Result<usize> {
+        detect(signal, 14)
+    }
+}
+
+impl Stream for Day06 {
+    fn stream(reader: impl BufRead) -> Result<(usize, usize)> {
+        let [packet, message] = detect_all(reader, [4, 14])?;
+        Ok((packet, message))
+    }
+}
+
+/// Position just after the first `size` distinct characters in a row.
+pub fn detect(data: &str, size: usize) -> Result<usize> {
+    Ok(detect_all(data.as_bytes(), [size])?[0])
+}
+
+/// Finds the marker of each size in one pass over `reader`, a buffer at a time, stopping once
+/// all of them are found.
+pub fn detect_all<const N: usize>(
+    mut reader: impl BufRead,
+    sizes: [usize; N],
+) -> Result<[usize; N]> {
+    let mut markers = sizes.map(Marker::new);
+    let mut at = 0;
+    while markers.iter().any(|m| m.found.is_none()) {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            break;
+        }
+        for &b in buf {
+            for marker in &mut markers {
+                marker.push(at, b);
+            }
+            at += 1;
+        }
+        let len = buf.len();
+        reader.consume(len);
+    }
+    let mut found = [0; N];
+    for (found, marker) in found.iter_mut().zip(markers) {
+        *found = marker.found.context("no marker found")?;
+    }
+    Ok(found)
+}
+
+/// Slides a window along the signal, jumping its start past the last repeat of each character
+/// it takes in, until the window holds `size` distinct characters.
+struct Marker {
+    size: usize,
+    // One past where each byte was last seen, so zero means never.
+    seen: [usize; 256],
+    start: usize,
+    found: Option<usize>,
+}
+
+impl Marker {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            seen: [0; 256],
+            start: 0,
+            found: None,
+        }
+    }
+
+    fn push(&mut self, i: usize, b: u8) {
+        if self.found.is_some() {
+            return;
+        }
+        self.start = self.start.max(self.seen[b as usize]);
+        self.seen[b as usize] = i + 1;
+        if i + 1 - self.start >= self.size {
+            self.found = Some(i + 1);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! The original implementations, kept to check the faster ones against.
+
+use anyhow::Result;
+
+/// Compares every pair of characters in every window.
+pub fn detect(data: &str, size: usize) -> Result<usize> {
+    let data: Vec<u8> = data.as_bytes().to_vec();
+    let windows = data.windows(size).enumerate();
+
+    'outer: for w in windows {
+        let (idx, w) = w;
+        let len = w.len();
+
+        for i in 0..len {
+            for j in i + 1..len {
+                if w[i] == w[j] {
+                    continue 'outer;
+                }
+            }
+        }
+        return Ok(idx + size);
+    }
+    Err(anyhow::Error::msg("no marker found"))
+}
@@ -0,0 +1,10 @@
+use day06::{detect, reference};
+use proptest::prelude::*;
+
+proptest! {
+    /// A small alphabet keeps repeats, and so near-miss windows, common.
+    #[test]
+    fn detect_matches_reference(signal in "[a-e]{0,40}", size in 1usize..8) {
+        prop_assert_eq!(detect(&signal, size).ok(), reference::detect(&signal, size).ok());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Day07;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+struct Dir {
+    files: Vec<usize>,
+    dirs: Vec<Dir>,
+}
+
+impl Generate for Day07 {
+    /// A transcript exploring a filesystem of about `size` files and directories. The disk is
+    /// between 40M and 70M full, so there's always a directory worth deleting.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut root = Dir {
+            files: Vec::new(),
+            dirs: Vec::new(),
+        };
+        let mut weights = Vec::new();
+        for _ in 0..size.max(1) {
+            let mut dir = &mut root;
+            while !dir.dirs.is_empty() && rng.gen_bool(0.7) {
+                let i = rng.gen_range(0..dir.dirs.len());
+                dir = &mut dir.dirs[i];
+            }
+            if rng.gen_bool(0.3) {
+                dir.dirs.push(Dir {
+                    files: Vec::new(),
+                    dirs: Vec::new(),
+                });
+            } else {
+                dir.files.push(weights.len());
+                weights.push(rng.gen_range(1..=100));
+            }
+        }
+        if weights.is_empty() {
+            root.files.push(0);
+            weights.push(1);
+        }
+
+        // Scale the file sizes so the disk is as full as the puzzle expects.
+        let total: usize = weights.iter().sum();
+        let target = rng.gen_range(40_100_000..69_900_000);
+        let sizes: Vec<usize> = weights
+            .iter()
+            .map(|w| (w * target / total).max(1))
+            .collect();
+
+        let mut out = String::from("$ cd /\n");
+        transcript(&root, &sizes, &mut out);
+        out
+    }
+}
+
+fn transcript(dir: &Dir, sizes: &[usize], out: &mut String) {
+    *out += "$ ls\n";
+    for i in 0..dir.dirs.len() {
+        *out += &format!("dir d{}\n", i);
+    }
+    for &f in &dir.files {
+        *out += &format!("{} f{}.txt\n", sizes[f], f);
+    }
+    for (i, sub) in dir.dirs.iter().enumerate() {
+        *out += &format!("$ cd d{}\n", i);
+        transcript(sub, sizes, out);
+        *out += "$ cd ..\n";
+    }
+}
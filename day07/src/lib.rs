@@ -0,0 +1,96 @@
+use anyhow::{Context, Result};
+use common::parse::{parse_at, split_at};
+use common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+/// Total size of each directory, keyed by absolute path.
+pub type Sizes = HashMap<String, usize>;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Sizes;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Sizes, ParseError> {
+        calculate_sizes(data)
+    }
+
+    fn part1(sizes: &Sizes) -> Result<usize> {
+        const LIMIT: usize = 100_000;
+
+        let sum = sizes.values().filter(|v| **v < LIMIT).sum();
+
+        Ok(sum)
+    }
+
+    fn part2(sizes: &Sizes) -> Result<usize> {
+        const MAX: usize = 70_000_000;
+        const NEEDED: usize = 30_000_000;
+
+        let used = sizes.get("/").context("no root size")?;
+        let available = MAX
+            .checked_sub(*used)
+            .with_context(|| format!("{} used, more than the disk's {}", used, MAX))?;
+        let Some(release) = NEEDED.checked_sub(available).filter(|&r| r > 0) else {
+            // There's already enough space, so nothing needs removing.
+            return Ok(0);
+        };
+
+        let remove_size = sizes
+            .values()
+            .filter(|v| **v >= release)
+            .min()
+            .context("no minimum")?;
+
+        Ok(*remove_size)
+    }
+}
+
+fn calculate_sizes(data: &str) -> Result<Sizes, ParseError> {
+    let mut path = PathBuf::from("/");
+    let mut sizes = Sizes::new();
+
+    for line in data.lines() {
+        match line {
+            // Skip
+            "$ cd /" | "$ ls" => {}
+            s if s.starts_with("dir") => {}
+
+            // Movement
+            "$ cd .." => {
+                path.pop();
+            }
+            s if s.starts_with("$ cd ") => {
+                path.push(s.strip_prefix("$ cd ").unwrap());
+            }
+            s if s.starts_with('$') => {
+                return Err(ParseError::at(data, s, "unknown command"));
+            }
+
+            // Sizes
+            s => {
+                let (size, _) = split_at(data, s, " ")?;
+                let size = parse_at::<usize>(data, size)?;
+
+                for ancestor in path.as_path().ancestors() {
+                    let ancestor = ancestor.to_string_lossy();
+                    sizes
+                        .entry(ancestor.into())
+                        .and_modify(|entry| *entry += size)
+                        .or_insert(size);
+                }
+            }
+        }
+    }
+
+    Ok(sizes)
+}
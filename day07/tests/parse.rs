@@ -0,0 +1,14 @@
+use common::testing::parse_error;
+use day07::Day07;
+
+#[test]
+fn reports_bad_terminal_lines_where_they_are() {
+    assert_eq!(
+        parse_error::<Day07>("$ cd /\n$ ls\ndir a\nabc b.txt\n"),
+        (4, 1, "abc".into())
+    );
+    assert_eq!(
+        parse_error::<Day07>("$ cd /\n$ foo\n"),
+        (2, 1, "$ foo".into())
+    );
+}
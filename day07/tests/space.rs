@@ -0,0 +1,24 @@
+use common::Solution;
+use day07::Day07;
+
+fn part2(data: &str) -> anyhow::Result<usize> {
+    Day07::part2(&Day07::parse(data).unwrap())
+}
+
+#[test]
+fn removes_nothing_when_there_is_already_room() {
+    assert_eq!(part2("$ cd /\n$ ls\n100 a.txt\n").unwrap(), 0);
+    assert_eq!(part2("$ cd /\n$ ls\n40000000 a.txt\n").unwrap(), 0);
+}
+
+#[test]
+fn more_than_the_disk_is_an_error() {
+    let err = part2("$ cd /\n$ ls\n70000001 a.txt\n").unwrap_err();
+    assert!(err.to_string().contains("more than the disk"), "{}", err);
+}
+
+#[test]
+fn removes_the_smallest_directory_that_makes_room() {
+    let data = "$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n2000000 x\n$ cd ..\n$ cd b\n$ ls\n15000000 y\n$ cd ..\n$ ls\n30000000 z\n";
+    assert_eq!(part2(data).unwrap(), 15000000);
+}
@@ -0,0 +1,28 @@
+use crate::{visible, Day08};
+use anyhow::Result;
+use grid::Grid;
+use viz::{Draw, Palette, Rgb};
+
+/// Visible trees are drawn as their height and hidden ones as the letter that many places after
+/// `a`, so both can be shaded by height.
+impl Draw for Day08 {
+    fn palette() -> Palette {
+        Palette::new(Rgb(0, 0, 0))
+            .gradient('0'..='9', Rgb(198, 239, 166), Rgb(24, 94, 32))
+            .gradient('a'..='j', Rgb(214, 214, 214), Rgb(64, 64, 64))
+    }
+
+    /// Both parts draw which trees are visible from outside the grid.
+    fn draw(m: &Grid<u32>, _: u8) -> Result<Grid<char>> {
+        let visible = visible(m);
+        let mut grid = Grid::new(m.width(), m.height(), ' ');
+        for (pos, &height) in m.iter() {
+            grid[pos] = if visible.contains(&pos) {
+                char::from_digit(height, 10).unwrap_or('9')
+            } else {
+                (b'a' + height as u8) as char
+            };
+        }
+        Ok(grid)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::Day08;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day08 {
+    /// A square forest `size` trees wide.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| char::from(b'0' + rng.gen_range(0..=9)))
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{Grid, Point};
+use std::collections::HashSet;
+
+mod draw;
+mod generate;
+pub mod reference;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(data, |c| {
+            c.to_digit(10).ok_or("tree heights must be digits")
+        })
+    }
+
+    fn part1(m: &Grid<u32>) -> Result<usize> {
+        Ok(visible(m).len())
+    }
+
+    fn part2(m: &Grid<u32>) -> Result<usize> {
+        let max = scenic_scores(m)
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .context("no maximum")?;
+
+        Ok(max)
+    }
+}
+
+fn edge_visible<'a>(
+    trees: impl DoubleEndedIterator<Item = &'a u32> + ExactSizeIterator + Clone,
+) -> HashSet<usize> {
+    let mut set = HashSet::new();
+    let mut max = (-1, -1);
+    let double_ended = trees.clone().enumerate().zip(trees.enumerate().rev());
+
+    for v in double_ended {
+        let ((fwd_i, fwd_v), (rev_i, rev_v)) = v;
+
+        let fwd_v = *fwd_v as i32;
+        if fwd_v > max.0 {
+            max.0 = fwd_v;
+            set.insert(fwd_i);
+        }
+
+        let rev_v = *rev_v as i32;
+        if rev_v > max.1 {
+            max.1 = rev_v;
+            set.insert(rev_i);
+        }
+    }
+    set
+}
+
+/// Trees visible from outside the grid.
+fn visible(m: &Grid<u32>) -> HashSet<Point> {
+    let row = visible_by_row(m);
+    let col = visible_by_col(m);
+
+    let mut combine = HashSet::<Point>::new();
+    combine.extend(&row);
+    combine.extend(&col);
+    combine
+}
+
+fn visible_by_row(m: &Grid<u32>) -> HashSet<Point> {
+    (0..m.height()).fold(HashSet::new(), |mut accum, y| {
+        for x in edge_visible(m.row(y).iter()) {
+            accum.insert(Point::new(x as i32, y as i32));
+        }
+        accum
+    })
+}
+
+fn visible_by_col(m: &Grid<u32>) -> HashSet<Point> {
+    (0..m.width()).fold(HashSet::new(), |mut accum, x| {
+        for y in edge_visible(m.column(x)) {
+            accum.insert(Point::new(x as i32, y as i32));
+        }
+        accum
+    })
+}
+
+/// Every tree's scenic score: the product of how far it can see in each direction.
+pub fn scenic_scores(m: &Grid<u32>) -> Grid<usize> {
+    let mut scores = Grid::new(m.width(), m.height(), 1);
+    let (width, height) = (m.width() as i32, m.height() as i32);
+    for y in 0..height {
+        look_back(m, (0..width).map(|x| Point::new(x, y)), &mut scores);
+        look_back(m, (0..width).rev().map(|x| Point::new(x, y)), &mut scores);
+    }
+    for x in 0..width {
+        look_back(m, (0..height).map(|y| Point::new(x, y)), &mut scores);
+        look_back(m, (0..height).rev().map(|y| Point::new(x, y)), &mut scores);
+    }
+    scores
+}
+
+/// Multiplies each score by how many trees its tree sees looking back along `line`. The stack
+/// keeps the trees not yet hidden behind a taller one, so each is pushed and popped once.
+fn look_back(m: &Grid<u32>, line: impl Iterator<Item = Point>, scores: &mut Grid<usize>) {
+    let mut stack: Vec<(usize, u32)> = Vec::new();
+    for (i, pos) in line.enumerate() {
+        let height = m[pos];
+        while stack.last().is_some_and(|&(_, h)| h < height) {
+            stack.pop();
+        }
+        scores[pos] *= stack.last().map_or(i, |&(j, _)| i - j);
+        stack.push((i, height));
+    }
+}
@@ -0,0 +1,33 @@
+//! The original implementations, kept to check the faster ones against.
+
+use grid::{Grid, Point};
+
+/// Walks out from `pos` along its row and column.
+pub fn scenic_score(m: &Grid<u32>, pos: Point) -> usize {
+    let (x, y) = (pos.x as usize, pos.y as usize);
+    let row_prod = position_visible(m.row(y).to_vec(), x);
+    let col_prod = position_visible(m.column(x).copied().collect(), y);
+    row_prod * col_prod
+}
+
+fn position_visible(trees: Vec<u32>, pos: usize) -> usize {
+    debug_assert!(trees.len() > pos);
+
+    let mut rev_sum = 0;
+    for v in trees[..pos].iter().rev() {
+        rev_sum += 1;
+        if *v >= trees[pos] {
+            break;
+        }
+    }
+
+    let mut fwd_sum = 0;
+    for v in trees[pos + 1..].iter() {
+        fwd_sum += 1;
+        if *v >= trees[pos] {
+            break;
+        }
+    }
+
+    rev_sum * fwd_sum
+}
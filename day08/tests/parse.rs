@@ -0,0 +1,8 @@
+use common::testing::parse_error;
+use day08::Day08;
+
+#[test]
+fn reports_bad_trees_where_they_are() {
+    assert_eq!(parse_error::<Day08>("123\n4a6\n"), (2, 2, "a".into()));
+    assert_eq!(parse_error::<Day08>("123\n45\n"), (2, 1, "45".into()));
+}
@@ -0,0 +1,27 @@
+use common::Solution;
+use day08::{reference, scenic_scores, Day08};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Rectangular forests of digits, shrinking towards smaller and shorter ones.
+fn forest() -> impl Strategy<Value = String> {
+    (1usize..8, 1usize..8)
+        .prop_flat_map(|(width, height)| vec(vec(0u32..10, width), height))
+        .prop_map(|rows| {
+            rows.iter()
+                .map(|row| row.iter().map(u32::to_string).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+}
+
+proptest! {
+    #[test]
+    fn scenic_scores_match_reference(data in forest()) {
+        let m = Day08::parse(&data).unwrap();
+        let scores = scenic_scores(&m);
+        for (pos, &score) in scores.iter() {
+            prop_assert_eq!(score, reference::scenic_score(&m, pos), "at {}", pos);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{simulate_rope, Day09, Move, Rope};
+use anyhow::Result;
+use grid::{Bounds, Grid, Point};
+use viz::{Animate, Draw, Frame, Palette, Recorder, Rgb};
+
+impl Animate for Day09 {
+    /// Follows the rope, drawing the cells its tail has visited.
+    fn animate(ops: &Vec<Move>, part: u8, recorder: &mut Recorder) -> Result<()> {
+        let knots = if part == 1 { 2 } else { 10 };
+
+        // A first run finds the area the rope covers, so every frame is drawn to the same size.
+        let mut bounds = Bounds::new(Point::ORIGIN);
+        simulate_rope(ops, knots, |rope| {
+            bounds = rope.knots.iter().fold(bounds, |b, &k| b.include(k));
+        })?;
+
+        let mut steps = 0;
+        let rope = simulate_rope(ops, knots, |rope| {
+            steps += 1;
+            recorder.record(|| draw(rope, bounds, steps));
+        })?;
+        recorder.finish(|| draw(&rope, bounds, steps));
+        Ok(())
+    }
+}
+
+/// Visited cells as `#` under the knots, with the head as `H` and the rest numbered from 1, or
+/// `T` for a two-knot rope's tail.
+fn draw(rope: &Rope, bounds: Bounds, steps: usize) -> Frame {
+    let mut grid = Grid::new(bounds.width(), bounds.height(), '.');
+    for &p in &rope.history {
+        grid[p - bounds.min] = '#';
+    }
+    grid[Point::ORIGIN - bounds.min] = 's';
+    for (i, &knot) in rope.knots.iter().enumerate().rev() {
+        grid[knot - bounds.min] = match i {
+            0 => 'H',
+            1 if rope.knots.len() == 2 => 'T',
+            i => char::from_digit(i as u32, 36).unwrap_or('*'),
+        };
+    }
+    Frame::new(
+        grid,
+        format!("step {}, tail visited {} cells", steps, rope.history.len()),
+    )
+}
+
+impl Draw for Day09 {
+    fn palette() -> Palette {
+        let knot = Rgb(255, 159, 28);
+        ('1'..='9').fold(
+            Palette::new(Rgb(16, 24, 40))
+                .with('#', Rgb(110, 170, 230))
+                .with('s', Rgb(255, 230, 90))
+                .with('H', Rgb(230, 57, 70))
+                .with('T', knot),
+            |palette, c| palette.with(c, knot),
+        )
+    }
+
+    /// The cells the tail visited, with the rope where it stopped.
+    fn draw(ops: &Vec<Move>, part: u8) -> Result<Grid<char>> {
+        Ok(viz::last_frame::<Self>(ops, part)?.grid)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::Day09;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day09 {
+    /// `size` head motions.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let dir = ["U", "D", "L", "R"].choose(rng).unwrap();
+                format!("{} {}\n", dir, rng.gen_range(1..=20))
+            })
+            .collect()
+    }
+}
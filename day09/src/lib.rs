@@ -0,0 +1,139 @@
+use anyhow::{Context, Result};
+use common::parse::{parse_at, split_at};
+use common::{ParseError, Solution, Stream};
+pub use grid::Direction;
+use grid::Point;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+mod animate;
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub type Move = (Direction, usize);
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(data: &str) -> Result<Vec<Move>, ParseError> {
+        parse_ops(data)
+    }
+
+    fn part1(ops: &Vec<Move>) -> Result<usize> {
+        Ok(simulate_rope(ops, 2, |_| {})?.history.len())
+    }
+
+    fn part2(ops: &Vec<Move>) -> Result<usize> {
+        Ok(simulate_rope(ops, 10, |_| {})?.history.len())
+    }
+}
+
+impl Stream for Day09 {
+    /// Pulls both ropes along together as the moves are read. Only the cells each tail has
+    /// visited are kept, however long the input.
+    fn stream(reader: impl BufRead) -> Result<(usize, usize)> {
+        let (mut short, mut long) = (Rope::new(2), Rope::new(10));
+        for op in moves(reader) {
+            let op = op?;
+            short.apply_move(op, |_| {})?;
+            long.apply_move(op, |_| {})?;
+        }
+        Ok((short.history.len(), long.history.len()))
+    }
+}
+
+/// Pulls a rope of `knots` through `ops`, calling `on_step` after every step of the head.
+fn simulate_rope(ops: &[Move], knots: usize, on_step: impl FnMut(&Rope)) -> Result<Rope> {
+    let mut rope = Rope::new(knots);
+    rope.apply(ops, on_step)?;
+    Ok(rope)
+}
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Point>,
+    history: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(count: usize) -> Self {
+        let knots = vec![Point::ORIGIN; count];
+        let history = HashSet::from([Point::ORIGIN]);
+        Self { knots, history }
+    }
+
+    fn apply(&mut self, ops: &[Move], mut on_step: impl FnMut(&Rope)) -> Result<()> {
+        for &op in ops {
+            self.apply_move(op, &mut on_step)?;
+        }
+        Ok(())
+    }
+
+    fn apply_move(&mut self, (dir, steps): Move, mut on_step: impl FnMut(&Rope)) -> Result<()> {
+        for _ in 0..steps {
+            self.step(dir)?;
+            on_step(self);
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, d: Direction) -> Result<()> {
+        self.step_head(d);
+        self.step_tail();
+        self.update_history()?;
+        Ok(())
+    }
+
+    fn step_head(&mut self, d: Direction) {
+        self.knots[0] += d.delta();
+    }
+
+    fn step_tail(&mut self) {
+        for i in 1..self.knots.len() {
+            follow(self.knots[i - 1], &mut self.knots[i]);
+        }
+    }
+
+    fn update_history(&mut self) -> Result<()> {
+        self.history.insert(*self.knots.last().context("tail")?);
+        Ok(())
+    }
+}
+
+fn follow(head: Point, tail: &mut Point) {
+    if head.chebyshev(*tail) > 1 {
+        *tail += (head - *tail).signum();
+    }
+}
+
+fn parse_ops(data: &str) -> Result<Vec<Move>, ParseError> {
+    moves(data.as_bytes()).collect()
+}
+
+/// The head's moves, read a line at a time.
+pub fn moves(reader: impl BufRead) -> impl Iterator<Item = Result<Move, ParseError>> {
+    common::parse::lines(reader).map(|line| {
+        let (n, line) = line?;
+        parse_move(&line).map_err(|err| err.on_line(n))
+    })
+}
+
+fn parse_move(line: &str) -> Result<Move, ParseError> {
+    let (dir, step) = split_at(line, line, " ")?;
+    let step = parse_at::<usize>(line, step)?;
+    let dir = match dir {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return Err(ParseError::at(line, dir, "expected U, D, L or R")),
+    };
+    Ok((dir, step))
+}
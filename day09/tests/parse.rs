@@ -0,0 +1,8 @@
+use common::testing::parse_error;
+use day09::Day09;
+
+#[test]
+fn reports_bad_moves_where_they_are() {
+    assert_eq!(parse_error::<Day09>("R 4\nQ 2\n"), (2, 1, "Q".into()));
+    assert_eq!(parse_error::<Day09>("R 4\nU x\n"), (2, 3, "x".into()));
+}
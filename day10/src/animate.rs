@@ -0,0 +1,28 @@
+use crate::{lit, run, Day10, Op};
+use anyhow::Result;
+use grid::{Grid, Point};
+use viz::{Animate, Frame, Recorder};
+
+impl Animate for Day10 {
+    /// Draws the CRT a pixel at a time, with the sprite's position on the row beneath. Both
+    /// parts run the same program, so they share an animation.
+    fn animate(ops: &Vec<Op>, _: u8, recorder: &mut Recorder) -> Result<()> {
+        let mut screen = Grid::new(40, 7, ' ');
+        let mut caption = String::new();
+        run(ops, |cycle, x| {
+            let pos = screen.pos(((cycle - 1) % (40 * 6)) as usize);
+            screen[pos] = if lit(cycle, x) { '#' } else { '.' };
+            for sx in 0..40 {
+                screen[Point::new(sx, 6)] = if (x - 1..=x + 1).contains(&(sx as i64)) {
+                    '='
+                } else {
+                    ' '
+                };
+            }
+            caption = format!("cycle {}, x = {}", cycle, x);
+            recorder.record(|| Frame::new(screen.clone(), caption.clone()));
+        });
+        recorder.finish(|| Frame::new(screen, caption));
+        Ok(())
+    }
+}
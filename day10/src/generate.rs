@@ -0,0 +1,16 @@
+use crate::Day10;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day10 {
+    /// A program of `size` instructions.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| match rng.gen_bool(0.6) {
+                true => format!("addx {}\n", rng.gen_range(-20..=20)),
+                false => "noop\n".into(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use common::parse::parse_at;
+use common::{ParseError, Solution, Stream};
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::io::BufRead;
+
+mod animate;
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Op>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(data: &str) -> Result<Vec<Op>, ParseError> {
+        parse(data)
+    }
+
+    /// Sums the signal strengths sampled during the program.
+    fn part1(ops: &Vec<Op>) -> Result<i64> {
+        Ok(samples(ops).values().sum())
+    }
+
+    /// Renders the CRT image drawn by the program.
+    fn part2(ops: &Vec<Op>) -> Result<String> {
+        Ok(render(ops))
+    }
+}
+
+#[derive(Debug)]
+pub enum Op {
+    Add(i64),
+    Noop,
+}
+
+impl Stream for Day10 {
+    /// Samples the signal and draws the CRT as each instruction is read.
+    fn stream(reader: impl BufRead) -> Result<(i64, String)> {
+        let (mut strength, mut crt) = (0, Crt::new());
+        let mut cpu = Cpu::new();
+        for op in ops(reader) {
+            cpu.exec(&op?, &mut |cycle, x| {
+                if SAMPLES.contains(&cycle) {
+                    strength += cycle as i64 * x;
+                }
+                crt.draw(cycle, x);
+            });
+        }
+        Ok((strength, crt.to_string()))
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<Op>, ParseError> {
+    ops(data.as_bytes()).collect()
+}
+
+/// The program's instructions, read a line at a time.
+pub fn ops(reader: impl BufRead) -> impl Iterator<Item = Result<Op, ParseError>> {
+    common::parse::lines(reader).map(|line| {
+        let (n, line) = line?;
+        parse_op(&line).map_err(|err| err.on_line(n))
+    })
+}
+
+fn parse_op(line: &str) -> Result<Op, ParseError> {
+    let chunks = line.split_whitespace().collect::<Vec<_>>();
+    match chunks[..] {
+        ["addx", n] => Ok(Op::Add(parse_at(line, n)?)),
+        ["noop"] => Ok(Op::Noop),
+        _ => Err(ParseError::at(
+            line,
+            line,
+            "expected \"addx N\" or \"noop\"",
+        )),
+    }
+}
+
+/// Cycles during which the signal strength is sampled.
+const SAMPLES: [u64; 6] = [20, 60, 100, 140, 180, 220];
+
+/// The `x` register and the clock.
+struct Cpu {
+    x: i64,
+    cycle: u64,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self { x: 1, cycle: 0 }
+    }
+
+    /// Executes `op`, calling `on_cycle` with each cycle's number, from 1, and the value of `x`
+    /// during it.
+    fn exec(&mut self, op: &Op, on_cycle: &mut impl FnMut(u64, i64)) {
+        match op {
+            Op::Add(n) => {
+                self.cycle += 1;
+                on_cycle(self.cycle, self.x);
+                self.cycle += 1;
+                on_cycle(self.cycle, self.x);
+                self.x = self.x.saturating_add(*n);
+            }
+            Op::Noop => {
+                self.cycle += 1;
+                on_cycle(self.cycle, self.x);
+            }
+        };
+    }
+}
+
+/// Runs the program, calling `on_cycle` for every cycle as [`Cpu::exec`] does.
+fn run(ops: &[Op], mut on_cycle: impl FnMut(u64, i64)) {
+    let mut cpu = Cpu::new();
+    for op in ops {
+        cpu.exec(op, &mut on_cycle);
+    }
+}
+
+fn samples(ops: &[Op]) -> HashMap<u64, i64> {
+    let mut samples = HashMap::new();
+    run(ops, |cycle, x| {
+        if SAMPLES.contains(&cycle) {
+            samples.insert(cycle, cycle as i64 * x);
+        }
+    });
+    samples
+}
+
+fn render(ops: &[Op]) -> String {
+    let mut crt = Crt::new();
+    run(ops, |cycle, x| crt.draw(cycle, x));
+    crt.to_string()
+}
+
+/// Whether the sprite centred on `x` covers the pixel drawn during `cycle`.
+fn lit(cycle: u64, x: i64) -> bool {
+    let pos = ((cycle - 1) % WIDTH as u64) as i64;
+    ((pos - 1)..=(pos + 1)).contains(&x)
+}
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+/// The CRT's screen. A program running past the last pixel wraps around and draws over the
+/// first, so the screen stays the same size however long the program is.
+struct Crt {
+    pixels: [bool; WIDTH * HEIGHT],
+    /// How many pixels have been drawn at least once.
+    drawn: usize,
+}
+
+impl Crt {
+    fn new() -> Self {
+        Self {
+            pixels: [false; WIDTH * HEIGHT],
+            drawn: 0,
+        }
+    }
+
+    fn draw(&mut self, cycle: u64, x: i64) {
+        let pos = ((cycle - 1) % self.pixels.len() as u64) as usize;
+        self.pixels[pos] = lit(cycle, x);
+        self.drawn = self.drawn.max(pos + 1);
+    }
+}
+
+impl fmt::Display for Crt {
+    /// The pixels drawn so far, a row per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, &lit) in self.pixels[..self.drawn].iter().enumerate() {
+            f.write_char(if lit { '#' } else { '.' })?;
+            if (i + 1) % WIDTH == 0 {
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Day11;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const PRIMES: [i64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+impl Generate for Day11 {
+    /// Notes on `size` monkeys, between 2 and 12. Operations never more than triple a worry
+    /// level, so part 1's division by three keeps worry from overflowing.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let monkeys = size.clamp(2, PRIMES.len());
+        let mut notes = Vec::new();
+        for (i, divisor) in PRIMES.iter().enumerate().take(monkeys) {
+            let items: Vec<String> = (0..rng.gen_range(1..=monkeys))
+                .map(|_| rng.gen_range(40..=99).to_string())
+                .collect();
+            let operation = match rng.gen_range(0..4) {
+                0 => "old + old".to_string(),
+                1 => format!("old * {}", rng.gen_range(2..=3)),
+                _ => format!("old + {}", rng.gen_range(1..=8)),
+            };
+            let mut target = || (i + rng.gen_range(1..monkeys)) % monkeys;
+            let (if_true, if_false) = (target(), target());
+            notes.push(format!(
+                "Monkey {}:
+  Starting items: {}
+  Operation: new = {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+                i,
+                items.join(", "),
+                operation,
+                divisor,
+                if_true,
+                if_false
+            ));
+        }
+        notes.join("\n")
+    }
+}
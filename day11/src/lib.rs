@@ -0,0 +1,235 @@
+use anyhow::{ensure, Result};
+use common::parse::parse_at;
+use common::{ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(data: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>) -> Result<i64> {
+        part1(monkeys)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> Result<i64> {
+        part2(monkeys)
+    }
+}
+
+fn part1(monkeys: &[Monkey]) -> Result<i64> {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let m = &mut monkeys[i];
+            let mut to_send = Vec::<(i64, i64)>::new();
+
+            while !m.items.is_empty() {
+                let item = m.items.remove(0);
+                m.items_seen += 1;
+
+                let new = match m.operator {
+                    Operator::Add => {
+                        item + match m.operand {
+                            Operand::Old => item,
+                            Operand::Int(n) => n,
+                        }
+                    }
+                    Operator::Mult => {
+                        item * match m.operand {
+                            Operand::Old => item,
+                            Operand::Int(n) => n,
+                        }
+                    }
+                };
+                let reduced = new / 3;
+                let to_monkey = match reduced % m.divisible_by {
+                    0 => m.true_monkey,
+                    _ => m.false_monkey,
+                };
+                to_send.push((to_monkey, reduced));
+            }
+
+            for (k, v) in &to_send {
+                let to_monkey = monkeys.get_mut(*k as usize).unwrap();
+                to_monkey.items.push(*v);
+            }
+        }
+    }
+
+    monkey_business(&monkeys)
+}
+
+fn part2(monkeys: &[Monkey]) -> Result<i64> {
+    let mut monkeys = monkeys.to_vec();
+    let reducer: i64 = monkeys.iter().map(|m| m.divisible_by).product();
+
+    for _ in 0..10000 {
+        for i in 0..monkeys.len() {
+            let m = &mut monkeys[i];
+            let mut to_send = Vec::<(i64, i64)>::new();
+
+            while !m.items.is_empty() {
+                let item = m.items.remove(0);
+                m.items_seen += 1;
+
+                let new = match m.operator {
+                    Operator::Add => {
+                        item + match m.operand {
+                            Operand::Old => item,
+                            Operand::Int(n) => n,
+                        }
+                    }
+                    Operator::Mult => {
+                        item * match m.operand {
+                            Operand::Old => item,
+                            Operand::Int(n) => n,
+                        }
+                    }
+                };
+                let to_monkey = match new % m.divisible_by {
+                    0 => m.true_monkey,
+                    _ => m.false_monkey,
+                };
+                to_send.push((to_monkey, new % reducer));
+            }
+
+            for (k, v) in &to_send {
+                let to_monkey = monkeys.get_mut(*k as usize).unwrap();
+                to_monkey.items.push(*v);
+            }
+        }
+    }
+
+    monkey_business(&monkeys)
+}
+
+/// Product of the two largest numbers of items any monkey inspected.
+fn monkey_business(monkeys: &[Monkey]) -> Result<i64> {
+    ensure!(
+        monkeys.len() >= 2,
+        "monkey business needs at least two monkeys"
+    );
+    let mut seen = monkeys.iter().map(|m| m.items_seen).collect::<Vec<_>>();
+    seen.sort();
+    seen.reverse();
+    Ok(seen[0..2].iter().product())
+}
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Mult,
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Int(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    items_seen: i64,
+    operator: Operator,
+    operand: Operand,
+    divisible_by: i64,
+    true_monkey: i64,
+    false_monkey: i64,
+}
+
+lazy_static! {
+    static ref CHUNK_PATTERN: Regex = Regex::new(
+        r"Monkey \d+:\s*
+  Starting items: (\d+(?:,\s+\d+)*)\s*
+  Operation: new = old (\*|\+) (old|\d+)\s*
+  Test: divisible by (\d+)\s*
+    If true: throw to monkey (\d+)\s*
+    If false: throw to monkey (\d+)",
+    )
+    .unwrap();
+}
+
+fn parse(data: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::<Monkey>::new();
+    let mut targets = Vec::new();
+
+    for chunk in data.split("\n\n").filter(|c| !c.trim().is_empty()) {
+        let cap = CHUNK_PATTERN.captures(chunk).ok_or_else(|| {
+            ParseError::at(
+                data,
+                chunk.lines().next().unwrap_or(chunk),
+                "expected a monkey",
+            )
+        })?;
+        let whole = cap.get(0).unwrap();
+        for extra in [&chunk[..whole.start()], &chunk[whole.end()..]] {
+            let extra = extra.trim();
+            if !extra.is_empty() {
+                let line = extra.lines().next().unwrap_or(extra);
+                return Err(ParseError::at(data, line, "unexpected text in a monkey"));
+            }
+        }
+        let items = cap
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|x| parse_at::<i64>(data, x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let operator = match &cap[2] {
+            "+" => Operator::Add,
+            _ => Operator::Mult,
+        };
+        let operand = match cap.get(3).unwrap().as_str() {
+            "old" => Operand::Old,
+            n => Operand::Int(parse_at(data, n)?),
+        };
+        let field = |i| cap.get(i).unwrap().as_str();
+        let divisible_by = parse_at(data, field(4))?;
+        if divisible_by == 0 {
+            return Err(ParseError::at(
+                data,
+                field(4),
+                "can't test divisibility by zero",
+            ));
+        }
+        let true_monkey = parse_at(data, field(5))?;
+        let false_monkey = parse_at(data, field(6))?;
+        targets.extend([field(5), field(6)]);
+
+        monkeys.push(Monkey {
+            items_seen: 0,
+            items,
+            operator,
+            operand,
+            divisible_by,
+            true_monkey,
+            false_monkey,
+        });
+    }
+
+    if let Some(target) = targets
+        .into_iter()
+        .find(|t| t.parse::<usize>().map_or(true, |n| n >= monkeys.len()))
+    {
+        return Err(ParseError::at(data, target, "no such monkey"));
+    }
+
+    Ok(monkeys)
+}
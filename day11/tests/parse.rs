@@ -0,0 +1,33 @@
+use common::testing::parse_error;
+use common::Solution;
+use day11::Day11;
+
+const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+#[test]
+fn reports_bad_monkeys_where_they_are() {
+    let data = format!("{}    garbage line here\n", MONKEY);
+    assert_eq!(
+        parse_error::<Day11>(&data),
+        (7, 5, "garbage line here".into())
+    );
+    let data = MONKEY.replace("If false: throw to monkey 0", "If false: throw to monkey 9");
+    assert_eq!(parse_error::<Day11>(&data), (6, 31, "9".into()));
+    let data = MONKEY.replace("divisible by 23", "divisible by 0");
+    assert_eq!(parse_error::<Day11>(&data), (4, 22, "0".into()));
+    let data = format!("{}\nMonkey 1:\n  Starting items: 54, 6x\n", MONKEY);
+    assert_eq!(parse_error::<Day11>(&data), (8, 1, "Monkey 1:".into()));
+}
+
+#[test]
+fn one_monkey_is_an_error_rather_than_a_panic() {
+    let monkeys = Day11::parse(MONKEY).unwrap();
+    assert!(Day11::part1(&monkeys).is_err());
+    assert!(Day11::part2(&monkeys).is_err());
+}
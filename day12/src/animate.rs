@@ -0,0 +1,111 @@
+use crate::{Day12, MetaGraph};
+use anyhow::{bail, Result};
+use grid::{Direction, Grid, Point};
+use petgraph::graph::NodeIndex;
+use petgraph::Direction::{Incoming, Outgoing};
+use std::collections::hash_map::{Entry, HashMap};
+use viz::{Animate, Draw, Frame, Palette, Recorder, Rgb};
+
+impl Animate for Day12 {
+    /// Searches breadth first, a frame per step of distance, then draws the shortest path over
+    /// the heightmap. Part 2 searches backwards from the end until it reaches an `a`.
+    fn animate(meta: &MetaGraph, part: u8, recorder: &mut Recorder) -> Result<()> {
+        let MetaGraph {
+            graph,
+            heights,
+            graph_nodes,
+            start,
+            end,
+        } = meta;
+        let (from, direction) = match part {
+            1 => (graph_nodes[*start], Outgoing),
+            _ => (graph_nodes[*end], Incoming),
+        };
+        let done = |node: NodeIndex| match part {
+            1 => node == graph_nodes[*end],
+            _ => heights[graph[node]] == 'a',
+        };
+
+        let mut came_from = HashMap::from([(from, from)]);
+        let mut layer = vec![from];
+        let mut steps = 0;
+        let found = loop {
+            recorder.record(|| {
+                let mut grid = heights.clone();
+                for node in came_from.keys() {
+                    grid[graph[*node]] = '.';
+                }
+                for node in &layer {
+                    grid[graph[*node]] = '*';
+                }
+                Frame::new(grid, format!("{} steps", steps))
+            });
+            if let Some(&node) = layer.iter().find(|&&node| done(node)) {
+                break node;
+            }
+
+            let mut next = Vec::new();
+            for &node in &layer {
+                for neighbor in graph.neighbors_directed(node, direction) {
+                    if let Entry::Vacant(entry) = came_from.entry(neighbor) {
+                        entry.insert(node);
+                        next.push(neighbor);
+                    }
+                }
+            }
+            layer = next;
+            steps += 1;
+            if layer.is_empty() {
+                bail!("no path");
+            }
+        };
+
+        let mut path = vec![graph[found]];
+        let mut at = found;
+        while at != from {
+            at = came_from[&at];
+            path.push(graph[at]);
+        }
+        if part == 1 {
+            path.reverse();
+        }
+        recorder.finish(|| draw_path(heights, &path, steps));
+        Ok(())
+    }
+}
+
+/// The heightmap with arrows along `path`.
+fn draw_path(heights: &Grid<char>, path: &[Point], steps: usize) -> Frame {
+    let mut grid = heights.clone();
+    for step in path.windows(2) {
+        let dir = Direction::ALL
+            .into_iter()
+            .find(|d| d.delta() == step[1] - step[0]);
+        grid[step[0]] = match dir {
+            Some(Direction::Up) => '^',
+            Some(Direction::Right) => '>',
+            Some(Direction::Down) => 'v',
+            Some(Direction::Left) => '<',
+            None => '?',
+        };
+    }
+    if let Some(&last) = path.last() {
+        grid[last] = 'E';
+    }
+    Frame::new(grid, format!("shortest path: {} steps", steps))
+}
+
+impl Draw for Day12 {
+    fn palette() -> Palette {
+        let path = Rgb(230, 57, 70);
+        ['^', '>', 'v', '<', 'E'].into_iter().fold(
+            Palette::new(Rgb(0, 0, 0)).gradient('a'..='z', Rgb(34, 85, 51), Rgb(245, 245, 245)),
+            |palette, c| palette.with(c, path),
+        )
+    }
+
+    /// The heightmap with the shortest path over it.
+    fn draw(meta: &MetaGraph, part: u8) -> Result<Grid<char>> {
+        Ok(viz::last_frame::<Self>(meta, part)?.grid)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Day12;
+use common::Generate;
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day12 {
+    /// A heightmap `size` squares wide, at least 26 so there's room to climb from `a` to `z`.
+    /// A winding trail climbs from `S` on the left edge to `E` on the right, so `E` is always
+    /// reachable.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let width = size.max(26);
+        let height = (width / 3).max(5);
+        let mut map = Grid::new(width, height, 'a');
+        for y in 0..height {
+            for x in 0..width {
+                map[Point::new(x as i32, y as i32)] = rng.gen_range(b'a'..=b'z') as char;
+            }
+        }
+
+        // Walk right one column at a time, wandering up and down within each column.
+        let mut trail = Vec::new();
+        let mut at = Point::new(0, rng.gen_range(0..height as i32));
+        for x in 0..width as i32 {
+            at.x = x;
+            trail.push(at);
+            let goal = rng.gen_range(0..height as i32);
+            while at.y != goal {
+                at.y += (goal - at.y).signum();
+                trail.push(at);
+            }
+        }
+
+        let last = trail.len() - 1;
+        for (i, &p) in trail.iter().enumerate() {
+            map[p] = (b'a' + (25 * i / last) as u8) as char;
+        }
+        map[trail[0]] = 'S';
+        map[trail[last]] = 'E';
+        map.to_string() + "\n"
+    }
+}
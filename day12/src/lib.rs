@@ -0,0 +1,120 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{Grid, Point};
+use petgraph::{algo::dijkstra, graph::Graph, graph::NodeIndex, prelude::*, visit::Reversed};
+
+mod animate;
+mod generate;
+pub mod reference;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = MetaGraph;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(data: &str) -> Result<MetaGraph, ParseError> {
+        new_graph(data)
+    }
+
+    fn part1(meta: &MetaGraph) -> Result<i32> {
+        let MetaGraph {
+            graph,
+            graph_nodes,
+            start,
+            end,
+            ..
+        } = meta;
+
+        let path = dijkstra(graph, graph_nodes[*start], Some(graph_nodes[*end]), |_| 1);
+        let steps = path.get(&graph_nodes[*end]).context("no entry")?;
+
+        Ok(*steps)
+    }
+
+    /// Searches backwards from the end once, rather than forwards from every `a`.
+    fn part2(meta: &MetaGraph) -> Result<i32> {
+        let MetaGraph {
+            graph,
+            heights,
+            graph_nodes,
+            end,
+            ..
+        } = meta;
+
+        let steps = dijkstra(Reversed(graph), graph_nodes[*end], None, |_| 1);
+        let min = heights
+            .iter()
+            .filter(|(_, &ch)| ch == 'a')
+            .filter_map(|(pos, _)| steps.get(&graph_nodes[heights.index(pos)?]))
+            .min()
+            .context("no minimum")?;
+
+        Ok(*min)
+    }
+}
+
+pub struct MetaGraph {
+    graph: Graph<Point, (), Directed>,
+    heights: Grid<char>,
+    graph_nodes: Vec<NodeIndex<u32>>,
+    start: usize,
+    end: usize,
+}
+
+fn new_graph(data: &str) -> Result<MetaGraph, ParseError> {
+    let mut heights = Grid::parse(data, |ch| match ch {
+        'a'..='z' | 'S' | 'E' => Ok(ch),
+        _ => Err("expected a height from a to z, S or E"),
+    })?;
+    let start = heights
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(data, "no start S"))?;
+    let end = heights
+        .position(|&ch| ch == 'E')
+        .ok_or_else(|| ParseError::at_end(data, "no end E"))?;
+    for (marker, what) in [('S', "start"), ('E', "end")] {
+        if let Some((pos, _)) = heights.iter().filter(|&(_, &ch)| ch == marker).nth(1) {
+            let line = data.lines().nth(pos.y as usize).unwrap_or_default();
+            let x = pos.x as usize;
+            let at = line.get(x..x + 1).unwrap_or(line);
+            return Err(ParseError::at(
+                data,
+                at,
+                format!("a second {} {}", what, marker),
+            ));
+        }
+    }
+    heights[start] = 'a';
+    heights[end] = 'z';
+
+    let mut graph: Graph<Point, (), Directed> = Graph::new();
+    let graph_nodes: Vec<NodeIndex<u32>> =
+        heights.iter().map(|(pos, _)| graph.add_node(pos)).collect();
+
+    for (a, &from) in heights.iter() {
+        for b in heights.neighbors4(a) {
+            let diff = heights[b] as i32 - from as i32;
+            if diff <= 1 {
+                graph.add_edge(
+                    graph_nodes[heights.index(a).unwrap()],
+                    graph_nodes[heights.index(b).unwrap()],
+                    (),
+                );
+            }
+        }
+    }
+
+    Ok(MetaGraph {
+        graph,
+        start: heights.index(start).unwrap(),
+        end: heights.index(end).unwrap(),
+        heights,
+        graph_nodes,
+    })
+}
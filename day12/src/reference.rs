@@ -0,0 +1,38 @@
+//! The original implementations, kept to check the faster ones against.
+
+use crate::MetaGraph;
+use anyhow::{Context, Result};
+use petgraph::algo::dijkstra;
+
+/// Searches from every `a` to the end in turn.
+pub fn part2(meta: &MetaGraph) -> Result<i32> {
+    let MetaGraph {
+        graph,
+        heights,
+        graph_nodes,
+        end,
+        ..
+    } = meta;
+
+    let a_indices: Vec<usize> = heights
+        .iter()
+        .filter_map(|(pos, &ch)| {
+            if ch == 'a' {
+                return heights.index(pos);
+            }
+            None
+        })
+        .collect();
+
+    let steps = a_indices
+        .iter()
+        .filter_map(|idx| {
+            let path = dijkstra(graph, graph_nodes[*idx], Some(graph_nodes[*end]), |_| 1);
+            let steps = path.get(&graph_nodes[*end])?;
+            Some(*steps)
+        })
+        .collect::<Vec<i32>>();
+    let min = steps.iter().min().context("no minimum")?;
+
+    Ok(*min)
+}
@@ -0,0 +1,9 @@
+use common::testing::parse_error;
+use day12::Day12;
+
+#[test]
+fn reports_repeated_start_and_end_markers() {
+    assert_eq!(parse_error::<Day12>("SabE\naSbz\n"), (2, 2, "S".into()));
+    assert_eq!(parse_error::<Day12>("SabE\nazbE\n"), (2, 4, "E".into()));
+    assert_eq!(parse_error::<Day12>("Sab?\n"), (1, 4, "?".into()));
+}
@@ -0,0 +1,37 @@
+use common::Solution;
+use day12::{reference, Day12};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Heightmaps from `a` to `e` with a start and an end in different places, shrinking towards
+/// smaller maps and lower ground.
+fn heightmap() -> impl Strategy<Value = String> {
+    (1usize..7, 2usize..7)
+        .prop_flat_map(|(width, height)| {
+            let cells = width * height;
+            (
+                Just(width),
+                vec(prop::char::range('a', 'e'), cells),
+                0..cells,
+                0..cells,
+            )
+        })
+        .prop_filter("start and end must differ", |(_, _, s, e)| s != e)
+        .prop_map(|(width, mut cells, start, end)| {
+            cells[start] = 'S';
+            cells[end] = 'E';
+            cells
+                .chunks(width)
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+}
+
+proptest! {
+    #[test]
+    fn part2_matches_reference(data in heightmap()) {
+        let meta = Day12::parse(&data).unwrap();
+        prop_assert_eq!(Day12::part2(&meta).ok(), reference::part2(&meta).ok());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Day13;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day13 {
+    /// `size` pairs of packets.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+fn packet(rng: &mut StdRng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| match depth < 4 && rng.gen_bool(0.3) {
+            true => packet(rng, depth + 1),
+            false => rng.gen_range(0..=10).to_string(),
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
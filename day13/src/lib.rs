@@ -0,0 +1,211 @@
+use anyhow::Result;
+use common::{ParseError, Solution};
+use serde::Deserialize;
+use std::cmp::{Ord, Ordering};
+
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Value {
+    List(Vec<Value>),
+    Digit(u32),
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+        Some(self.cmp(o))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, o: &Self) -> Ordering {
+        match (self, o) {
+            (Self::Digit(a), Self::Digit(b)) => a.cmp(b),
+            (Self::Digit(a), b) => Self::List(vec![Self::Digit(*a)]).cmp(b),
+            (a, Self::Digit(b)) => a.cmp(&Self::List(vec![Self::Digit(*b)])),
+            (Self::List(a), Self::List(b)) => {
+                let mut a_iter = a.iter();
+                let mut b_iter = b.iter();
+                loop {
+                    match (a_iter.next(), b_iter.next()) {
+                        (Some(a), Some(b)) => match a.cmp(b) {
+                            Ordering::Equal => continue,
+                            ord => return ord,
+                        },
+                        (None, Some(_)) => return Ordering::Less,
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, None) => return Ordering::Equal,
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Value, Value)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(data: &str) -> Result<Vec<(Value, Value)>, ParseError> {
+        let mut pairs = Vec::new();
+        for p in data.split("\n\n") {
+            let lines: Vec<&str> = p.lines().collect();
+            let [line0, line1] = lines[..] else {
+                return Err(ParseError::at(data, p, "expected a pair of packets"));
+            };
+            pairs.push((lex(data, line0)?, lex(data, line1)?));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<(Value, Value)>) -> Result<i32> {
+        let mut in_order = Vec::new();
+        for (i, (list0, list1)) in pairs.iter().enumerate() {
+            if list0 < list1 {
+                in_order.push((i + 1) as i32);
+            }
+        }
+
+        Ok(in_order.iter().sum())
+    }
+
+    fn part2(pairs: &Vec<(Value, Value)>) -> Result<i32> {
+        let mut all = Vec::new();
+        for (list0, list1) in pairs {
+            all.push(list0.clone());
+            all.push(list1.clone());
+        }
+
+        all.push(Value::List(vec![Value::List(vec![Value::Digit(2)])]));
+        all.push(Value::List(vec![Value::List(vec![Value::Digit(6)])]));
+
+        all.sort();
+        let markers: Vec<_> = all
+            .iter()
+            .enumerate()
+            .filter_map(|t| {
+                if is_marker(2, t.1) || is_marker(6, t.1) {
+                    return Some((t.0 + 1) as i32);
+                }
+                None
+            })
+            .collect();
+
+        Ok(markers.iter().product())
+    }
+}
+
+fn is_marker(d: u32, v: &Value) -> bool {
+    match v {
+        Value::List(v) => {
+            if v.len() != 1 {
+                return false;
+            }
+            match &v[0] {
+                Value::List(v) => {
+                    if v.len() != 1 {
+                        return false;
+                    }
+                    if let Value::Digit(v) = v[0] {
+                        return v == d;
+                    }
+                    false
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[derive(PartialEq)]
+enum LexState {
+    Stopped,
+    Scanning,
+    InDigit,
+}
+
+fn lex(data: &str, line: &str) -> Result<Value, ParseError> {
+    let chars = line.char_indices();
+
+    let mut parents = Vec::new();
+    let mut current_list: Value = Value::List(vec![]);
+    let mut current_digit: Value = Value::Digit(0);
+    let mut state = LexState::Stopped;
+    let mut closed = false;
+
+    for (i, ch) in chars {
+        let here = &line[i..i + ch.len_utf8()];
+        if closed {
+            return Err(ParseError::at(
+                data,
+                here,
+                "unexpected text after the packet",
+            ));
+        }
+        if state == LexState::Stopped && ch != '[' {
+            return Err(ParseError::at(data, here, "expected '['"));
+        }
+        match ch {
+            '[' => {
+                if state == LexState::Scanning {
+                    parents.push(current_list);
+                }
+                state = LexState::Scanning;
+                current_list = Value::List(Vec::new());
+            }
+            ']' => {
+                if state == LexState::InDigit {
+                    if let Value::List(v) = &mut current_list {
+                        v.push(current_digit.clone());
+                    }
+                }
+                match parents.pop() {
+                    Some(mut parent) => {
+                        if let Value::List(v) = &mut parent {
+                            v.push(current_list.clone());
+                        }
+                        current_list = parent;
+                    }
+                    None => closed = true,
+                }
+                state = LexState::Scanning;
+                current_digit = Value::Digit(0);
+            }
+            ',' => {
+                if state == LexState::InDigit {
+                    if let Value::List(v) = &mut current_list {
+                        v.push(current_digit.clone());
+                    }
+                }
+                state = LexState::Scanning;
+                current_digit = Value::Digit(0);
+            }
+            ch => {
+                let digit = ch
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::at(data, here, "expected a digit"))?;
+                if let Value::Digit(d) = current_digit {
+                    let d = d
+                        .checked_mul(10)
+                        .and_then(|d| d.checked_add(digit))
+                        .ok_or_else(|| ParseError::at(data, here, "number too large"))?;
+                    current_digit = Value::Digit(d);
+                }
+                state = LexState::InDigit;
+            }
+        }
+    }
+
+    if !closed {
+        return Err(ParseError::at(data, &line[line.len()..], "expected ']'"));
+    }
+    Ok(current_list)
+}
@@ -0,0 +1,20 @@
+use common::testing::parse_error;
+use common::Solution;
+use day13::Day13;
+
+#[test]
+fn reports_unbalanced_packets() {
+    assert_eq!(parse_error::<Day13>("[1\n[2]\n"), (1, 3, "".into()));
+    assert_eq!(parse_error::<Day13>("[1]]\n[2]\n"), (1, 4, "]".into()));
+    assert_eq!(parse_error::<Day13>("[1]\n1,2\n"), (2, 1, "1".into()));
+    assert_eq!(parse_error::<Day13>("[1]\n[2]x\n"), (2, 4, "x".into()));
+}
+
+#[test]
+fn reports_numbers_too_large_for_a_packet() {
+    assert!(Day13::parse("[1]\n[4294967295]\n").is_ok());
+    assert_eq!(
+        parse_error::<Day13>("[1]\n[42949672950]\n"),
+        (2, 12, "0".into())
+    );
+}
@@ -0,0 +1,48 @@
+use crate::{pour, Cave, Day14, Material};
+use anyhow::Result;
+use common::Unsolved;
+use grid::{Grid, Point};
+use viz::{Animate, Draw, Frame, Palette, Recorder, Rgb};
+
+impl Animate for Day14 {
+    /// Pours the sand a move at a time, with the falling grain as `~`.
+    fn animate(cave: &Cave, part: u8, recorder: &mut Recorder) -> Result<()> {
+        if part != 1 {
+            return Err(Unsolved.into());
+        }
+        // Every grain's first move takes it to the row below the entrance.
+        let mut grains = 0;
+        let (units, volume) = pour(cave, |volume, grain| {
+            if grain.y == cave.entrance.y + 1 {
+                grains += 1;
+            }
+            recorder.record(|| draw(cave, volume, Some(grain), format!("grain {}", grains)));
+        });
+        recorder.finish(|| draw(cave, &volume, None, format!("{} units at rest", units)));
+        Ok(())
+    }
+}
+
+fn draw(cave: &Cave, volume: &Grid<Material>, grain: Option<Point>, caption: String) -> Frame {
+    let mut grid = volume.map(Material::symbol);
+    grid[cave.entrance] = '+';
+    if let Some(grain) = grain {
+        grid[grain] = '~';
+    }
+    Frame::new(grid, caption)
+}
+
+impl Draw for Day14 {
+    fn palette() -> Palette {
+        Palette::new(Rgb(20, 20, 28))
+            .with('#', Rgb(120, 120, 130))
+            .with('o', Rgb(236, 200, 120))
+            .with('~', Rgb(236, 200, 120))
+            .with('+', Rgb(230, 57, 70))
+    }
+
+    /// The cave once the sand has settled.
+    fn draw(cave: &Cave, part: u8) -> Result<Grid<char>> {
+        Ok(viz::last_frame::<Self>(cave, part)?.grid)
+    }
+}
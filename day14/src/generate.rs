@@ -0,0 +1,26 @@
+use crate::Day14;
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day14 {
+    /// `size` rock paths below and around the sand's entrance at 500,0.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut x: i32 = rng.gen_range(470..=530);
+                let mut y: i32 = rng.gen_range(2..=60);
+                let mut points = vec![format!("{},{}", x, y)];
+                for i in 0..rng.gen_range(1..=4) {
+                    if i % 2 == 0 {
+                        x = (x + rng.gen_range(-8..=8)).clamp(450, 550);
+                    } else {
+                        y = (y + rng.gen_range(-6..=6)).clamp(2, 70);
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use common::parse::{parse_at, split_at};
+use common::{ParseError, Solution, Unsolved};
+use grid::{Bounds, Grid, Point};
+
+mod animate;
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+#[derive(Clone, Debug)]
+enum Material {
+    Air,
+    Sand,
+    Rock,
+}
+
+impl Material {
+    fn symbol(&self) -> char {
+        match self {
+            Self::Air => '.',
+            Self::Sand => 'o',
+            Self::Rock => '#',
+        }
+    }
+}
+
+impl std::fmt::Display for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// The cave's rock formations, with the sand entrance in grid coordinates.
+#[derive(Debug)]
+pub struct Cave {
+    volume: Grid<Material>,
+    entrance: Point,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Cave, ParseError> {
+        let mut entrance = Point::new(500, 0);
+        let volume = build_volume(data, &mut entrance)?;
+        Ok(Cave { volume, entrance })
+    }
+
+    fn part1(cave: &Cave) -> Result<u32> {
+        Ok(pour(cave, |_, _| {}).0)
+    }
+
+    fn part2(_: &Cave) -> Result<u32> {
+        Err(Unsolved.into())
+    }
+}
+
+/// Pours sand until it falls into the abyss or blocks the entrance, calling `on_step` with the
+/// volume and the falling grain after every move. Returns the units that came to rest and the
+/// volume they filled.
+fn pour(cave: &Cave, mut on_step: impl FnMut(&Grid<Material>, Point)) -> (u32, Grid<Material>) {
+    let Cave { volume, entrance } = cave;
+    let mut volume = volume.clone();
+
+    let mut units = 0;
+    'production: loop {
+        let mut new = *entrance;
+        'stepping: loop {
+            match step(&volume, &new) {
+                Ok(p) => new = p,
+                Err(Stop::InAbyss) => break 'production,
+                Err(Stop::AtRest) => break 'stepping,
+            }
+            on_step(&volume, new);
+        }
+
+        // Sand unable to enter the room.
+        if new == *entrance {
+            break;
+        }
+
+        volume[new] = Material::Sand;
+        units += 1;
+    }
+
+    (units, volume)
+}
+
+#[derive(Debug)]
+enum Stop {
+    AtRest,
+    InAbyss,
+}
+
+fn step(volume: &Grid<Material>, p: &Point) -> Result<Point, Stop> {
+    let search = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+    for candidate in search.map(|d| *p + d) {
+        match volume.get(candidate).ok_or(Stop::InAbyss)? {
+            Material::Air => return Ok(candidate),
+            Material::Rock | Material::Sand => {}
+        }
+    }
+    Err(Stop::AtRest)
+}
+
+fn build_volume(data: &str, entrance: &mut Point) -> Result<Grid<Material>, ParseError> {
+    // Build coordinate list so we can find the dimensions of the room.
+    let mut ledges = Vec::new();
+    for l in data.lines() {
+        let mut ledge: Vec<Point> = Vec::new();
+        let points = l.split(" -> ").collect::<Vec<&str>>();
+        for p in points {
+            let (x, y) = split_at(data, p, ",")?;
+            let point = Point::new(parse_at(data, x)?, parse_at(data, y)?);
+            if let Some(prev) = ledge.last() {
+                if prev.x != point.x && prev.y != point.y {
+                    return Err(ParseError::at(
+                        data,
+                        p,
+                        "ledges must be horizontal or vertical",
+                    ));
+                }
+            }
+            ledge.push(point);
+        }
+        ledges.push(ledge);
+    }
+    let bounds = Bounds::of(ledges.iter().flatten().copied())
+        .ok_or_else(|| ParseError::at_end(data, "no ledges"))?
+        .include(*entrance);
+
+    // Normalize entrance coordinates to grid.
+    *entrance -= bounds.min;
+
+    // Fill the volume with air.
+    let mut volume = Grid::new(bounds.width(), bounds.height(), Material::Air);
+
+    // Draw the ledges.
+    for w in &ledges {
+        for p in w.windows(2) {
+            let (mut at, stop) = (p[0] - bounds.min, p[1] - bounds.min);
+            let dir = (stop - at).signum();
+            volume[at] = Material::Rock;
+            while at != stop {
+                at += dir;
+                volume[at] = Material::Rock;
+            }
+        }
+    }
+
+    Ok(volume)
+}
@@ -0,0 +1,11 @@
+use common::testing::parse_error;
+use day14::Day14;
+
+#[test]
+fn reports_bad_paths_where_they_are() {
+    assert_eq!(
+        parse_error::<Day14>("498,4 -> 498,6\n503,4 -> 50x,4\n"),
+        (2, 10, "50x".into())
+    );
+    assert_eq!(parse_error::<Day14>("498,4 -> 498,6\n503,4 502,4\n").0, 2);
+}
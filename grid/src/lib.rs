@@ -0,0 +1,200 @@
+use common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub mod point;
+
+pub use point::{Bounds, Direction, Direction8, Point};
+
+/// A rectangular grid of cells stored row by row in a single vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line and one cell per character. Every line must be the same, nonzero
+    /// width.
+    pub fn parse<E: fmt::Display>(
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in text.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let c =
+                    cell(c).map_err(|err| ParseError::at(text, &line[i..i + c.len_utf8()], err))?;
+                cells.push(c);
+            }
+            let len = cells.len() - before;
+            if len == 0 {
+                return Err(ParseError::at(text, line, "empty row"));
+            }
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => {
+                    let message = format!("row is {} cells wide, expected {}", len, w);
+                    return Err(ParseError::at(text, line, message));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Self {
+                cells,
+                width,
+                height,
+            }),
+            None => Err(ParseError::at_end(text, "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(self.width as i32 - 1, self.height as i32 - 1),
+        }
+    }
+
+    /// Index of a position into the flat storage, if it's inside the grid.
+    pub fn index(&self, p: Point) -> Option<usize> {
+        let x = usize::try_from(p.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(p.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Position of an index into the flat storage.
+    pub fn pos(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.index(p).is_some()
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Moves `p` by `step`, if that stays inside the grid.
+    pub fn offset(&self, p: Point, step: Point) -> Option<Point> {
+        Some(p + step).filter(|&p| self.contains(p))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, v)| (self.pos(i), v))
+    }
+
+    /// Position of the first cell, row by row, that satisfies `pred`.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(pred).map(|i| self.pos(i))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Positions above, right, below and left of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |d| self.offset(p, d.delta()))
+    }
+
+    /// Positions of the eight cells surrounding `p` that are inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .iter()
+            .filter_map(move |d| self.offset(p, d.delta()))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Cells reached by repeatedly adding `step` to `p` until leaving the grid, not including `p`
+    /// itself. Panics if `step` is zero, since the ray would never leave.
+    pub fn ray(&self, p: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        assert!(step != Point::ORIGIN, "ray with a zero step");
+        std::iter::successors(self.offset(p, step), move |&p| self.offset(p, step))
+            .map(|p| (p, &self[p]))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{} outside {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} outside {}x{} grid", p, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use grid::{Grid, Point};
+use std::convert::Infallible;
+
+fn chars(text: &str) -> Grid<char> {
+    Grid::parse(text, Ok::<_, Infallible>).unwrap()
+}
+
+/// Where parsing `text` failed: line, column and message.
+fn error(text: &str) -> (usize, usize, String) {
+    let Err(err) = Grid::parse(text, Ok::<_, Infallible>) else {
+        panic!("grid should be rejected");
+    };
+    (err.line, err.column, err.message)
+}
+
+/// Sorted positions, so neighbours can be compared regardless of order.
+fn sorted(points: impl Iterator<Item = Point>) -> Vec<(i32, i32)> {
+    let mut points: Vec<_> = points.map(|p| (p.x, p.y)).collect();
+    points.sort();
+    points
+}
+
+#[test]
+fn parses_rows_of_cells() {
+    let grid = chars("abc\ndef\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 'f');
+    assert_eq!(grid.to_string(), "abc\ndef");
+}
+
+#[test]
+fn rejects_ragged_and_empty_input() {
+    assert_eq!(
+        error("abc\nde\nfgh\n"),
+        (2, 1, "row is 2 cells wide, expected 3".into())
+    );
+    assert_eq!(error(""), (1, 1, "empty grid".into()));
+    assert_eq!(error("\nabc\n"), (1, 1, "empty row".into()));
+    assert_eq!(error("abc\n\nabc\n"), (2, 1, "empty row".into()));
+}
+
+#[test]
+fn reports_bad_cells_where_they_are() {
+    let Err(err) = Grid::parse("..\n.x\n", |c| match c {
+        '.' => Ok(0),
+        _ => Err("bad cell"),
+    }) else {
+        panic!("grid should be rejected");
+    };
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+}
+
+#[test]
+fn neighbors_stay_inside_at_the_corners() {
+    let grid = chars("abc\ndef\nghi\n");
+    assert_eq!(sorted(grid.neighbors4(Point::new(0, 0))), [(0, 1), (1, 0)]);
+    assert_eq!(sorted(grid.neighbors4(Point::new(2, 2))), [(1, 2), (2, 1)]);
+    assert_eq!(
+        sorted(grid.neighbors8(Point::new(0, 0))),
+        [(0, 1), (1, 0), (1, 1)]
+    );
+    assert_eq!(
+        sorted(grid.neighbors8(Point::new(2, 0))),
+        [(1, 0), (1, 1), (2, 1)]
+    );
+    assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn columns_read_top_to_bottom() {
+    let grid = chars("abc\ndef\nghi\n");
+    assert_eq!(grid.column(1).collect::<String>(), "beh");
+    assert_eq!(grid.column(2).rev().collect::<String>(), "ifc");
+    assert_eq!(grid.column(0).len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "column 3 out of bounds")]
+fn columns_past_the_edge_panic() {
+    let _ = chars("abc\n").column(3);
+}
+
+#[test]
+fn rays_stop_at_the_edge() {
+    let grid = chars("abcd\nefgh\nijkl\n");
+    let ray = |p, step| -> String { grid.ray(p, step).map(|(_, c)| *c).collect() };
+    assert_eq!(ray(Point::new(1, 0), Point::new(1, 0)), "cd");
+    assert_eq!(ray(Point::new(0, 0), Point::new(1, 1)), "fk");
+    assert_eq!(ray(Point::new(0, 0), Point::new(2, 0)), "c");
+    assert_eq!(ray(Point::new(3, 2), Point::new(1, 0)), "");
+    let last = grid.ray(Point::new(0, 1), Point::new(1, 0)).last().unwrap();
+    assert_eq!(last, (Point::new(3, 1), &'h'));
+}
+
+#[test]
+#[should_panic(expected = "zero step")]
+fn rays_with_a_zero_step_panic() {
+    let _ = chars("ab\n").ray(Point::ORIGIN, Point::ORIGIN);
+}
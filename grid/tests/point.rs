@@ -0,0 +1,59 @@
+use grid::{Bounds, Direction, Direction8, Point};
+
+#[test]
+fn distances() {
+    let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+    assert_eq!(a.manhattan(b), 10);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!(b.manhattan(a), 10);
+    assert_eq!(b.chebyshev(a), 6);
+    assert_eq!(a.manhattan(a), 0);
+    assert_eq!(Point::ORIGIN.chebyshev(Point::new(-5, 5)), 5);
+}
+
+#[test]
+fn turns_go_round_clockwise() {
+    use Direction::*;
+    for (d, right, left, opposite) in [
+        (Up, Right, Left, Down),
+        (Right, Down, Up, Left),
+        (Down, Left, Right, Up),
+        (Left, Up, Down, Right),
+    ] {
+        assert_eq!(d.turn_right(), right, "{:?}", d);
+        assert_eq!(d.turn_left(), left, "{:?}", d);
+        assert_eq!(d.opposite(), opposite, "{:?}", d);
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.opposite().delta(), -d.delta());
+    }
+}
+
+#[test]
+fn orthogonal_steps_match_their_compass_points() {
+    for d in Direction::ALL {
+        assert_eq!(Direction8::from(d).delta(), d.delta());
+    }
+    let total = Direction8::ALL
+        .iter()
+        .fold(Point::ORIGIN, |sum, d| sum + d.delta());
+    assert_eq!(total, Point::ORIGIN);
+}
+
+#[test]
+fn bounds_cover_every_point() {
+    let points = [Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)];
+    let bounds = Bounds::of(points).unwrap();
+    assert_eq!(bounds.min, Point::new(-3, -1));
+    assert_eq!(bounds.max, Point::new(2, 4));
+    assert_eq!((bounds.width(), bounds.height()), (6, 6));
+    assert!(points.iter().all(|&p| bounds.contains(p)));
+    assert!(!bounds.contains(Point::new(3, 0)));
+    assert!(!bounds.contains(Point::new(0, -2)));
+}
+
+#[test]
+fn bounds_of_one_point_or_none() {
+    let bounds = Bounds::of([Point::new(5, 7)]).unwrap();
+    assert_eq!((bounds.width(), bounds.height()), (1, 1));
+    assert_eq!(Bounds::of([]), None);
+}
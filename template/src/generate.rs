@@ -0,0 +1,13 @@
+use crate::{{crate_name | upper_camel_case}};
+use common::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for {{crate_name | upper_camel_case}} {
+    /// `size` lines of random numbers. Replace with the puzzle's input format.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.gen_range(0..1000)))
+            .collect()
+    }
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use common::{ParseError, Solution, Unsolved};
+
+mod generate;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "data/"]
+pub struct Data;
+
+pub struct {{crate_name | upper_camel_case}};
+
+impl Solution for {{crate_name | upper_camel_case}} {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(data: &str) -> Result<Vec<String>, ParseError> {
+        Ok(data.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Result<u32> {
+        Err(Unsolved.into())
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<u32> {
+        Err(Unsolved.into())
+    }
+}
@@ -1,12 +1,6 @@
-use anyhow::{Context, Result};
-
-#[derive(rust_embed::RustEmbed)]
-#[folder = "data/"]
-struct Data;
+use anyhow::Result;
+use {{crate_name}}::{Data, {{crate_name | upper_camel_case}}};
 
 fn main() -> Result<()> {
-    let file = Data::get("example.txt").context("file not found")?;
-    let _data = std::str::from_utf8(file.data.as_ref())?;
-
-    Ok(())
+    common::report::main::<{{crate_name | upper_camel_case}}, Data>(env!("CARGO_PKG_NAME"))
 }
@@ -0,0 +1,18 @@
+use common::Solution;
+use {{crate_name}}::{{crate_name | upper_camel_case}};
+
+const EXAMPLE: &str = include_str!("../data/example.txt");
+
+#[test]
+#[ignore = "fill in the example's part 1 answer"]
+fn part1_example() {
+    let input = {{crate_name | upper_camel_case}}::parse(EXAMPLE).unwrap();
+    assert_eq!({{crate_name | upper_camel_case}}::part1(&input).unwrap().to_string(), "");
+}
+
+#[test]
+#[ignore = "fill in the example's part 2 answer"]
+fn part2_example() {
+    let input = {{crate_name | upper_camel_case}}::parse(EXAMPLE).unwrap();
+    assert_eq!({{crate_name | upper_camel_case}}::part2(&input).unwrap().to_string(), "");
+}
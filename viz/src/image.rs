@@ -0,0 +1,148 @@
+use anyhow::{bail, Context, Result};
+use grid::{Grid, Point};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// A colour with 8 bits per channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The colour a fraction `t` of the way from `self` to `other`.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+impl std::fmt::Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The colour of each kind of cell, with a fallback for any kind not listed.
+#[derive(Clone, Debug)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Palette {
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, cell: char, color: Rgb) -> Self {
+        self.colors.insert(cell, color);
+        self
+    }
+
+    /// Shades `cells` evenly from `from` to `to`.
+    pub fn gradient(self, cells: impl IntoIterator<Item = char>, from: Rgb, to: Rgb) -> Self {
+        let cells: Vec<char> = cells.into_iter().collect();
+        let steps = cells.len().saturating_sub(1).max(1) as f64;
+        cells
+            .into_iter()
+            .enumerate()
+            .fold(self, |palette, (i, cell)| {
+                palette.with(cell, from.mix(to, i as f64 / steps))
+            })
+    }
+
+    pub fn color(&self, cell: char) -> Rgb {
+        self.colors.get(&cell).copied().unwrap_or(self.default)
+    }
+}
+
+/// Image formats a grid can be saved as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Svg,
+    Ppm,
+}
+
+impl Format {
+    /// The format named by a path's extension.
+    pub fn of(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("svg") => Ok(Self::Svg),
+            Some("ppm") => Ok(Self::Ppm),
+            _ => bail!("{}: expected a .svg or .ppm file", path.display()),
+        }
+    }
+}
+
+/// An SVG drawing each cell as a `scale`-pixel square. Runs of a colour along a row share one
+/// rectangle, and cells in the palette's fallback colour are left to the background.
+pub fn svg(grid: &Grid<char>, palette: &Palette, scale: usize) -> String {
+    let (width, height) = (grid.width(), grid.height());
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+        width * scale,
+        height * scale,
+        width,
+        height
+    );
+    let _ = writeln!(
+        out,
+        r#"<rect width="{}" height="{}" fill="{}"/>"#,
+        width, height, palette.default
+    );
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let color = palette.color(row[x]);
+            let run = row[x..]
+                .iter()
+                .take_while(|&&c| palette.color(c) == color)
+                .count();
+            if color != palette.default {
+                let _ = writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="1" fill="{}"/>"#,
+                    x, y, run, color
+                );
+            }
+            x += run;
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// A binary (P6) PPM drawing each cell as a `scale`-pixel square.
+pub fn ppm(grid: &Grid<char>, palette: &Palette, scale: usize) -> Vec<u8> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.reserve(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let cell = grid[Point::new((x / scale) as i32, (y / scale) as i32)];
+            let Rgb(r, g, b) = palette.color(cell);
+            out.extend([r, g, b]);
+        }
+    }
+    out
+}
+
+/// Writes `grid` to `path` in the format its extension names.
+pub fn save(path: &Path, grid: &Grid<char>, palette: &Palette, scale: usize) -> Result<()> {
+    let scale = scale.max(1);
+    let bytes = match Format::of(path)? {
+        Format::Svg => svg(grid, palette, scale).into_bytes(),
+        Format::Ppm => ppm(grid, palette, scale),
+    };
+    std::fs::write(path, bytes).with_context(|| format!("writing {}", path.display()))
+}
@@ -0,0 +1,37 @@
+//! Watching simulations step by step: days hand their intermediate states to a [`Recorder`]
+//! through a callback, and [`play`] shows the frames in the terminal. Grids can also be saved as
+//! SVG or PPM images with [`image::save`].
+
+use anyhow::{Context, Result};
+use common::Solution;
+use grid::Grid;
+
+pub mod image;
+pub mod play;
+pub mod record;
+
+pub use image::{Palette, Rgb};
+pub use play::play;
+pub use record::{Frame, Recorder};
+
+/// A day whose solution can be watched as it runs.
+pub trait Animate: Solution {
+    /// Solves `part` of `input`, offering each intermediate state to `recorder`.
+    fn animate(input: &Self::Input, part: u8, recorder: &mut Recorder) -> Result<()>;
+}
+
+/// A day whose state can be saved as an image.
+pub trait Draw: Solution {
+    /// The colour of each kind of cell in the grids [`Draw::draw`] returns.
+    fn palette() -> Palette;
+
+    /// A picture of `part` of `input`.
+    fn draw(input: &Self::Input, part: u8) -> Result<Grid<char>>;
+}
+
+/// The last frame of an animation, for days that draw their final state.
+pub fn last_frame<S: Animate>(input: &S::Input, part: u8) -> Result<Frame> {
+    let mut recorder = Recorder::new(2);
+    S::animate(input, part, &mut recorder)?;
+    recorder.into_frames().pop().context("nothing was recorded")
+}
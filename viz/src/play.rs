@@ -0,0 +1,137 @@
+use crate::Frame;
+use anyhow::{bail, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue, style::Print, tty::IsTty};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 1000.0;
+
+/// Plays `frames` in the terminal at `fps` frames per second, stopping on the last one until
+/// `q` is pressed. Space pauses, the arrow keys step a frame at a time, `+` and `-` double or
+/// halve the speed and `r` starts again.
+///
+/// When stdout isn't a terminal every frame is printed in turn instead.
+pub fn play(frames: &[Frame], fps: f64) -> Result<()> {
+    if frames.is_empty() {
+        bail!("no frames to play");
+    }
+    let mut stdout = io::stdout();
+    if !stdout.is_tty() {
+        for frame in frames {
+            writeln!(stdout, "{}\n{}\n", frame.caption, frame.grid)?;
+        }
+        return Ok(());
+    }
+
+    let _screen = Screen::enter()?;
+    let last = frames.len() - 1;
+    let (mut at, mut fps, mut paused) = (0, fps.clamp(MIN_FPS, MAX_FPS), false);
+    loop {
+        draw(&mut stdout, &frames[at], at, frames.len(), fps, paused)?;
+        let wait = (!paused && at < last).then(|| Duration::from_secs_f64(1.0 / fps));
+        let key = match next_input(wait)? {
+            Input::Tick => {
+                at += 1;
+                continue;
+            }
+            Input::Resize => continue,
+            Input::Key(key) => key,
+        };
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Char(' ') => paused = !paused,
+            KeyCode::Right | KeyCode::Char('.') => {
+                paused = true;
+                at = (at + 1).min(last);
+            }
+            KeyCode::Left | KeyCode::Char(',') => {
+                paused = true;
+                at = at.saturating_sub(1);
+            }
+            KeyCode::Up | KeyCode::Char('+') | KeyCode::Char('=') => fps = (fps * 2.0).min(MAX_FPS),
+            KeyCode::Down | KeyCode::Char('-') => fps = (fps / 2.0).max(MIN_FPS),
+            KeyCode::Home | KeyCode::Char('r') => at = 0,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Raw mode on the alternate screen, restored when dropped even if playback fails.
+struct Screen;
+
+impl Screen {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), terminal::LeaveAlternateScreen, cursor::Show);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+enum Input {
+    Tick,
+    Resize,
+    Key(KeyEvent),
+}
+
+/// Waits for a key press, or until `wait` has passed if given.
+fn next_input(wait: Option<Duration>) -> Result<Input> {
+    let deadline = wait.map(|wait| Instant::now() + wait);
+    loop {
+        if let Some(deadline) = deadline {
+            if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+                return Ok(Input::Tick);
+            }
+        }
+        match event::read()? {
+            Event::Key(key) => return Ok(Input::Key(key)),
+            Event::Resize(..) => return Ok(Input::Resize),
+            _ => {}
+        }
+    }
+}
+
+/// Draws as much of `frame` as fits above two status lines.
+fn draw(
+    out: &mut impl Write,
+    frame: &Frame,
+    at: usize,
+    count: usize,
+    fps: f64,
+    paused: bool,
+) -> Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let (columns, rows) = (columns as usize, (rows as usize).saturating_sub(2));
+    queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+    for row in frame.grid.rows().take(rows) {
+        let line: String = row.iter().take(columns).collect();
+        queue!(out, Print(line), Print("\r\n"))?;
+    }
+
+    let status = format!(
+        "{}  frame {}/{}  {} fps{}",
+        frame.caption,
+        at + 1,
+        count,
+        fps,
+        if paused { "  (paused)" } else { "" }
+    );
+    let help = "space pause  \u{2190}/\u{2192} step  +/- speed  r restart  q quit";
+    for line in [status.as_str(), help] {
+        let line: String = line.chars().take(columns).collect();
+        queue!(out, Print(line), Print("\r\n"))?;
+    }
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use grid::Grid;
+
+/// One picture of a simulation, with a line describing it.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>, caption: impl Into<String>) -> Self {
+        Self {
+            grid,
+            caption: caption.into(),
+        }
+    }
+}
+
+/// Collects frames from a simulation, keeping at most `limit` of them. Once full it drops every
+/// other frame and from then on keeps only every other state offered, so a long run is sampled
+/// evenly from start to finish.
+#[derive(Debug)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    limit: usize,
+    stride: usize,
+    offered: usize,
+    kept_last: bool,
+}
+
+impl Recorder {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            frames: Vec::new(),
+            limit: limit.max(2),
+            stride: 1,
+            offered: 0,
+            kept_last: false,
+        }
+    }
+
+    /// Offers the next state. `render` only runs for the states that are kept, so simulations
+    /// can call this on every step.
+    pub fn record(&mut self, render: impl FnOnce() -> Frame) {
+        self.kept_last = self.offered.is_multiple_of(self.stride);
+        self.offered += 1;
+        if !self.kept_last {
+            return;
+        }
+        self.frames.push(render());
+        if self.frames.len() > self.limit {
+            let mut i = 0;
+            self.frames.retain(|_| {
+                i += 1;
+                i % 2 == 1
+            });
+            self.stride *= 2;
+            self.kept_last = (self.offered - 1).is_multiple_of(self.stride);
+        }
+    }
+
+    /// Offers the final state, which is always kept. It replaces the last state offered if that
+    /// was kept, so a simulation can finish by redrawing its last step, or the last frame kept if
+    /// there's no room for another.
+    pub fn finish(&mut self, render: impl FnOnce() -> Frame) {
+        if self.kept_last || self.frames.len() >= self.limit {
+            self.frames.pop();
+        }
+        self.frames.push(render());
+        self.kept_last = true;
+    }
+
+    /// How many states were offered, kept or not.
+    pub fn offered(&self) -> usize {
+        self.offered
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
@@ -0,0 +1,76 @@
+use grid::Grid;
+use std::convert::Infallible;
+use viz::image::{ppm, svg, Format};
+use viz::{Palette, Rgb};
+
+const RED: Rgb = Rgb(255, 0, 0);
+const BLUE: Rgb = Rgb(0, 0, 255);
+const BLACK: Rgb = Rgb(0, 0, 0);
+
+fn grid(text: &str) -> Grid<char> {
+    Grid::parse(text, Ok::<_, Infallible>).unwrap()
+}
+
+fn palette() -> Palette {
+    Palette::new(BLACK).with('a', RED).with('b', BLUE)
+}
+
+#[test]
+fn ppm_has_a_header_and_three_bytes_per_pixel() {
+    let grid = grid("ab.\n.ba\n");
+    for scale in [1, 3] {
+        let image = ppm(&grid, &palette(), scale);
+        let (width, height) = (3 * scale, 2 * scale);
+        let header = format!("P6\n{} {}\n255\n", width, height);
+        assert!(image.starts_with(header.as_bytes()));
+        assert_eq!(image.len(), header.len() + width * height * 3);
+    }
+}
+
+#[test]
+fn ppm_pixels_take_their_cells_colour() {
+    let image = ppm(&grid("ab\n.a\n"), &palette(), 2);
+    let pixels = &image["P6\n4 4\n255\n".len()..];
+    let pixel = |x: usize, y: usize| {
+        let i = (y * 4 + x) * 3;
+        Rgb(pixels[i], pixels[i + 1], pixels[i + 2])
+    };
+    assert_eq!(pixel(0, 0), RED);
+    assert_eq!(pixel(1, 1), RED);
+    assert_eq!(pixel(2, 0), BLUE);
+    assert_eq!(pixel(3, 1), BLUE);
+    assert_eq!(pixel(1, 2), BLACK);
+    assert_eq!(pixel(3, 3), RED);
+}
+
+#[test]
+fn svg_merges_runs_of_one_colour() {
+    let image = svg(&grid("aab\nbbb\n..a\n"), &palette(), 4);
+    assert!(image.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="12" height="12""#));
+    let rects: Vec<&str> = image.lines().filter(|l| l.starts_with("<rect")).collect();
+    assert_eq!(
+        rects,
+        [
+            r##"<rect width="3" height="3" fill="#000000"/>"##,
+            r##"<rect x="0" y="0" width="2" height="1" fill="#ff0000"/>"##,
+            r##"<rect x="2" y="0" width="1" height="1" fill="#0000ff"/>"##,
+            r##"<rect x="0" y="1" width="3" height="1" fill="#0000ff"/>"##,
+            r##"<rect x="2" y="2" width="1" height="1" fill="#ff0000"/>"##,
+        ]
+    );
+    assert!(image.ends_with("</svg>\n"));
+}
+
+#[test]
+fn cells_sharing_a_colour_share_a_rectangle() {
+    let palette = palette().with('c', RED);
+    let image = svg(&grid("acca\n"), &palette, 1);
+    assert!(image.contains(r##"<rect x="0" y="0" width="4" height="1" fill="#ff0000"/>"##));
+}
+
+#[test]
+fn formats_come_from_the_extension() {
+    assert_eq!(Format::of("day08.svg".as_ref()).unwrap(), Format::Svg);
+    assert_eq!(Format::of("out/day12.ppm".as_ref()).unwrap(), Format::Ppm);
+    assert!(Format::of("day14.png".as_ref()).is_err());
+}
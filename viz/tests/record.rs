@@ -0,0 +1,81 @@
+use grid::Grid;
+use viz::{Frame, Recorder};
+
+fn frame(caption: impl Into<String>) -> Frame {
+    Frame::new(Grid::new(1, 1, '.'), caption)
+}
+
+fn captions(recorder: &Recorder) -> Vec<&str> {
+    recorder
+        .frames()
+        .iter()
+        .map(|f| f.caption.as_str())
+        .collect()
+}
+
+#[test]
+fn keeps_every_state_while_there_is_room() {
+    let mut recorder = Recorder::new(10);
+    for i in 0..5 {
+        recorder.record(|| frame(i.to_string()));
+    }
+    assert_eq!(captions(&recorder), ["0", "1", "2", "3", "4"]);
+    assert_eq!(recorder.offered(), 5);
+}
+
+#[test]
+fn stays_within_the_limit_from_first_to_last() {
+    for limit in 2..12 {
+        for states in 0..100 {
+            let mut recorder = Recorder::new(limit);
+            for i in 0..states {
+                recorder.record(|| frame(i.to_string()));
+                assert!(recorder.frames().len() <= limit);
+            }
+            recorder.finish(|| frame("end"));
+
+            let captions = captions(&recorder);
+            assert!(
+                captions.len() <= limit,
+                "{} frames of {} states with limit {}",
+                captions.len(),
+                states,
+                limit
+            );
+            assert_eq!(captions.last(), Some(&"end"));
+            // A single state is the last step too, so finishing replaces it.
+            if states > 1 {
+                assert_eq!(captions[0], "0", "{} states with limit {}", states, limit);
+            }
+            let sampled: Vec<usize> = captions[..captions.len() - 1]
+                .iter()
+                .map(|c| c.parse().unwrap())
+                .collect();
+            assert!(sampled.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
+
+#[test]
+fn finishing_replaces_a_kept_last_step() {
+    let mut recorder = Recorder::new(10);
+    for i in 0..3 {
+        recorder.record(|| frame(i.to_string()));
+    }
+    recorder.finish(|| frame("end"));
+    assert_eq!(captions(&recorder), ["0", "1", "end"]);
+}
+
+#[test]
+fn skipped_states_are_never_rendered() {
+    let mut recorder = Recorder::new(4);
+    let mut rendered = 0;
+    for i in 0..1000 {
+        recorder.record(|| {
+            rendered += 1;
+            frame(i.to_string())
+        });
+    }
+    assert_eq!(recorder.offered(), 1000);
+    assert!(rendered <= 25, "rendered {} of 1000 states", rendered);
+}